@@ -0,0 +1,176 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::private;
+
+/// A type-level alignment of `N` bytes.
+///
+/// `Align<N>` implements [`Alignment`] for each `N` which is a power of two
+/// from 1 up to and including 128.
+#[derive(Clone, Copy, Debug)]
+pub struct Align<const N: usize>(());
+
+/// An alignment supported by [`AlignTo`].
+///
+/// This trait is sealed, and is implemented by [`Align<N>`] for each
+/// supported alignment.
+pub trait Alignment: private::Sealed {
+    #[doc(hidden)]
+    type Marker: Clone + Copy + core::fmt::Debug + Default + Eq + core::hash::Hash + Ord;
+}
+
+macro_rules! alignments {
+    ($($n:literal => $marker:ident,)*) => {
+        $(
+            #[doc(hidden)]
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            #[repr(align($n))]
+            pub struct $marker;
+
+            impl private::Sealed for Align<$n> {}
+
+            impl Alignment for Align<$n> {
+                type Marker = $marker;
+            }
+        )*
+    };
+}
+
+alignments! {
+    1 => Align1,
+    2 => Align2,
+    4 => Align4,
+    8 => Align8,
+    16 => Align16,
+    32 => Align32,
+    64 => Align64,
+    128 => Align128,
+}
+
+/// A wrapper which raises the alignment of `T` to at least `N` bytes.
+///
+/// `AlignTo` is laid out as a zero-sized field with an alignment of `N`,
+/// followed by the wrapped value. Its size is that of `T`, rounded up to
+/// a multiple of the new alignment. The alignment of `T` is never lowered.
+///
+/// `N` must be a power of two no greater than 128.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::align_of;
+/// use struct_pad::AlignTo;
+///
+/// let value = AlignTo::<u8, 64>::new(7);
+/// assert_eq!(*value, 7);
+/// assert_eq!(align_of::<AlignTo<u8, 64>>(), 64);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct AlignTo<T, const N: usize>
+where
+    Align<N>: Alignment,
+{
+    align: <Align<N> as Alignment>::Marker,
+    value: T,
+}
+
+impl<T, const N: usize> AlignTo<T, N>
+where
+    Align<N>: Alignment,
+{
+    /// Wraps `value`, raising its alignment to at least `N` bytes.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self {
+            align: Default::default(),
+            value,
+        }
+    }
+
+    /// Unwraps the inner value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, const N: usize> Deref for AlignTo<T, N>
+where
+    Align<N>: Alignment,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, const N: usize> DerefMut for AlignTo<T, N>
+where
+    Align<N>: Alignment,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn align() {
+        assert_eq!(align_of::<AlignTo<u8, 1>>(), 1);
+        assert_eq!(align_of::<AlignTo<u8, 64>>(), 64);
+        assert_eq!(align_of::<AlignTo<u8, 128>>(), 128);
+        assert_eq!(align_of::<AlignTo<u64, 2>>(), align_of::<u64>());
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(size_of::<AlignTo<(), 16>>(), 0);
+        assert_eq!(size_of::<AlignTo<u8, 64>>(), 64);
+        assert_eq!(size_of::<AlignTo<[u8; 65], 64>>(), 128);
+    }
+
+    #[test]
+    fn deref() {
+        let mut value = AlignTo::<u8, 64>::new(7);
+        assert_eq!(*value, 7);
+        *value += 1;
+        assert_eq!(value.into_inner(), 8);
+    }
+
+    #[test]
+    fn matrix() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C, align(16))]
+        struct Aligned16([u8; 20]);
+
+        macro_rules! check {
+            ($ty:ty, $value:expr, [$($n:literal),*]) => {
+                $(
+                    let expected = if $n > align_of::<$ty>() { $n } else { align_of::<$ty>() };
+                    assert_eq!(align_of::<AlignTo<$ty, $n>>(), expected);
+                    assert_eq!(size_of::<AlignTo<$ty, $n>>() % expected, 0);
+                    assert!(size_of::<AlignTo<$ty, $n>>() >= size_of::<$ty>());
+                    assert!(size_of::<AlignTo<$ty, $n>>() < size_of::<$ty>() + expected);
+                    assert_eq!(*AlignTo::<$ty, $n>::new($value), $value);
+                )*
+            };
+        }
+
+        check!((), (), [1, 2, 4, 8, 16, 32, 64]);
+        check!(u8, 7u8, [1, 2, 4, 8, 16, 32, 64]);
+        check!([u8; 100], [7; 100], [1, 2, 4, 8, 16, 32, 64]);
+        check!(Aligned16, Aligned16([7; 20]), [1, 2, 4, 8, 16, 32, 64]);
+
+        assert_eq!(size_of::<AlignTo<u8, 32>>(), 32);
+        assert_eq!(size_of::<AlignTo<[u8; 100], 64>>(), 128);
+        assert_eq!(align_of::<AlignTo<Aligned16, 4>>(), 16);
+        assert_eq!(size_of::<AlignTo<Aligned16, 4>>(), size_of::<Aligned16>());
+    }
+}
@@ -0,0 +1,587 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::size_of;
+
+use crate::{Pad, PadU0, PadU16, PadU32, PadU64, PadU8};
+
+/// A type-erased padding type.
+///
+/// `AnyPad` has one variant for each of the builtin padding types,
+/// allowing the choice of padding to be made at runtime.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnyPad {
+    /// Corresponds to [`PadU0`].
+    U0,
+    /// Corresponds to [`PadU8`].
+    U8,
+    /// Corresponds to [`PadU16`].
+    U16,
+    /// Corresponds to [`PadU32`].
+    U32,
+    /// Corresponds to [`PadU64`].
+    U64,
+}
+
+impl AnyPad {
+    /// Returns the size in bytes of the corresponding padding type.
+    #[inline]
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::U0 => size_of::<PadU0>(),
+            Self::U8 => size_of::<PadU8>(),
+            Self::U16 => size_of::<PadU16>(),
+            Self::U32 => size_of::<PadU32>(),
+            Self::U64 => size_of::<PadU64>(),
+        }
+    }
+
+    /// Returns `true` if the corresponding padding type is the same size
+    /// as `usize`, i.e. if it is [`PadUsize`](crate::PadUsize) on the
+    /// current target.
+    #[inline]
+    #[must_use]
+    pub const fn is_pointer_sized(self) -> bool {
+        self.size() == size_of::<usize>()
+    }
+
+    /// Returns the position of `self` in [`BUILTIN_PADS`].
+    ///
+    /// The index is stable, so it may be used to index arrays parallel
+    /// to [`BUILTIN_PADS`].
+    #[inline]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        match self {
+            Self::U0 => 0,
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U32 => 3,
+            Self::U64 => 4,
+        }
+    }
+
+    /// Returns the builtin padding type at position `index` in
+    /// [`BUILTIN_PADS`], if any.
+    ///
+    /// This is the inverse of [`AnyPad::index`].
+    #[inline]
+    #[must_use]
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index < BUILTIN_PAD_COUNT {
+            Some(BUILTIN_PADS[index])
+        } else {
+            None
+        }
+    }
+}
+
+/// Every builtin padding type, in increasing order of size.
+pub const BUILTIN_PADS: &[AnyPad] = &[
+    AnyPad::U0,
+    AnyPad::U8,
+    AnyPad::U16,
+    AnyPad::U32,
+    AnyPad::U64,
+];
+
+/// The number of builtin padding types.
+///
+/// This is the length of [`BUILTIN_PADS`], and may be used to size arrays
+/// indexed by builtin padding type.
+pub const BUILTIN_PAD_COUNT: usize = BUILTIN_PADS.len();
+
+/// A bitmask of the sizes of the builtin padding types.
+///
+/// Bit `n` is set if a builtin padding type of `n` bytes exists, which
+/// allows finding an available size with bit tricks, as
+/// [`nearest_available_size`] does.
+pub const AVAILABLE_PAD_SIZES_MASK: u64 = {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < BUILTIN_PAD_COUNT {
+        mask |= 1 << BUILTIN_PADS[i].size();
+        i += 1;
+    }
+    mask
+};
+
+/// Returns the smallest size of a builtin padding type which is at least
+/// `n` bytes, if any.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::nearest_available_size;
+///
+/// assert_eq!(nearest_available_size(3), Some(4));
+/// assert_eq!(nearest_available_size(4), Some(4));
+/// assert_eq!(nearest_available_size(9), None);
+/// ```
+#[inline]
+#[must_use]
+pub const fn nearest_available_size(n: usize) -> Option<usize> {
+    if n >= u64::BITS as usize {
+        return None;
+    }
+    match AVAILABLE_PAD_SIZES_MASK >> n {
+        0 => None,
+        rest => Some(n + rest.trailing_zeros() as usize),
+    }
+}
+
+/// The error returned when converting an [`AnyPad`] into
+/// a padding type of a different size.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PadMismatch {
+    expected: usize,
+    actual: usize,
+}
+
+impl PadMismatch {
+    /// Returns the size in bytes of the requested padding type.
+    #[inline]
+    #[must_use]
+    pub const fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Returns the size in bytes of the padding type that was found.
+    #[inline]
+    #[must_use]
+    pub const fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for PadMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a {}-byte pad, found a {}-byte pad",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl From<PadU0> for AnyPad {
+    #[inline]
+    fn from(_: PadU0) -> Self {
+        Self::U0
+    }
+}
+
+impl From<PadU8> for AnyPad {
+    #[inline]
+    fn from(_: PadU8) -> Self {
+        Self::U8
+    }
+}
+
+impl From<PadU16> for AnyPad {
+    #[inline]
+    fn from(_: PadU16) -> Self {
+        Self::U16
+    }
+}
+
+impl From<PadU32> for AnyPad {
+    #[inline]
+    fn from(_: PadU32) -> Self {
+        Self::U32
+    }
+}
+
+impl From<PadU64> for AnyPad {
+    #[inline]
+    fn from(_: PadU64) -> Self {
+        Self::U64
+    }
+}
+
+impl TryFrom<AnyPad> for PadU0 {
+    type Error = PadMismatch;
+
+    #[inline]
+    fn try_from(pad: AnyPad) -> Result<Self, Self::Error> {
+        match pad {
+            AnyPad::U0 => Ok(Self::VALUE),
+            _ => Err(PadMismatch {
+                expected: size_of::<Self>(),
+                actual: pad.size(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<AnyPad> for PadU8 {
+    type Error = PadMismatch;
+
+    #[inline]
+    fn try_from(pad: AnyPad) -> Result<Self, Self::Error> {
+        match pad {
+            AnyPad::U8 => Ok(Self::VALUE),
+            _ => Err(PadMismatch {
+                expected: size_of::<Self>(),
+                actual: pad.size(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<AnyPad> for PadU16 {
+    type Error = PadMismatch;
+
+    #[inline]
+    fn try_from(pad: AnyPad) -> Result<Self, Self::Error> {
+        match pad {
+            AnyPad::U16 => Ok(Self::VALUE),
+            _ => Err(PadMismatch {
+                expected: size_of::<Self>(),
+                actual: pad.size(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<AnyPad> for PadU32 {
+    type Error = PadMismatch;
+
+    #[inline]
+    fn try_from(pad: AnyPad) -> Result<Self, Self::Error> {
+        match pad {
+            AnyPad::U32 => Ok(Self::VALUE),
+            _ => Err(PadMismatch {
+                expected: size_of::<Self>(),
+                actual: pad.size(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<AnyPad> for PadU64 {
+    type Error = PadMismatch;
+
+    #[inline]
+    fn try_from(pad: AnyPad) -> Result<Self, Self::Error> {
+        match pad {
+            AnyPad::U64 => Ok(Self::VALUE),
+            _ => Err(PadMismatch {
+                expected: size_of::<Self>(),
+                actual: pad.size(),
+            }),
+        }
+    }
+}
+
+/// Looks up a builtin padding type by its name.
+///
+/// Returns `None` if `name` is not the name of a builtin padding type.
+/// `"PadUsize"` resolves to whichever variant matches the width of `usize`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{pad_by_name, AnyPad};
+///
+/// assert_eq!(pad_by_name("PadU32"), Some(AnyPad::U32));
+/// assert_eq!(pad_by_name("PadU24"), None);
+/// ```
+#[must_use]
+pub fn pad_by_name(name: &str) -> Option<AnyPad> {
+    match name {
+        "PadU0" => Some(AnyPad::U0),
+        "PadU8" => Some(AnyPad::U8),
+        "PadU16" => Some(AnyPad::U16),
+        "PadU32" => Some(AnyPad::U32),
+        "PadU64" => Some(AnyPad::U64),
+        #[cfg(target_pointer_width = "16")]
+        "PadUsize" => Some(AnyPad::U16),
+        #[cfg(target_pointer_width = "32")]
+        "PadUsize" => Some(AnyPad::U32),
+        #[cfg(target_pointer_width = "64")]
+        "PadUsize" => Some(AnyPad::U64),
+        _ => None,
+    }
+}
+
+/// Splits `size` bytes of padding into builtin padding types.
+///
+/// The returned iterator yields the largest padding types first,
+/// and yields nothing if `size` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{decompose, AnyPad};
+///
+/// let mut pads = decompose(13);
+/// assert_eq!(pads.next(), Some(AnyPad::U64));
+/// assert_eq!(pads.next(), Some(AnyPad::U32));
+/// assert_eq!(pads.next(), Some(AnyPad::U8));
+/// assert_eq!(pads.next(), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn decompose(size: usize) -> Decompose {
+    Decompose { remaining: size }
+}
+
+/// Returns the number of padding types [`decompose`] splits `size` bytes
+/// of padding into.
+///
+/// This may be used to size a buffer for [`decompose_into`].
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{decompose, decompose_len};
+///
+/// // `PadU64`, `PadU32`, and `PadU8`.
+/// assert_eq!(decompose_len(13), 3);
+/// assert_eq!(decompose_len(13), decompose(13).count());
+/// ```
+#[inline]
+#[must_use]
+pub const fn decompose_len(size: usize) -> usize {
+    size / 8 + (size % 8).count_ones() as usize
+}
+
+/// Writes the padding types [`decompose`] splits `size` bytes of padding
+/// into to the start of `out`, without allocating.
+///
+/// Returns the number of padding types in the decomposition, which is
+/// [`decompose_len(size)`](decompose_len). If that exceeds the length of
+/// `out`, nothing is written; comparing the result against the length of
+/// the buffer detects this case.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{decompose_into, AnyPad};
+///
+/// let mut buffer = [AnyPad::U0; 4];
+/// let len = decompose_into(13, &mut buffer);
+/// assert_eq!(buffer[..len], [AnyPad::U64, AnyPad::U32, AnyPad::U8]);
+///
+/// let mut small = [AnyPad::U0; 2];
+/// assert!(decompose_into(13, &mut small) > small.len());
+/// ```
+#[inline]
+pub fn decompose_into(size: usize, out: &mut [AnyPad]) -> usize {
+    let len = decompose_len(size);
+    if len <= out.len() {
+        for (slot, pad) in out.iter_mut().zip(decompose(size)) {
+            *slot = pad;
+        }
+    }
+    len
+}
+
+/// An iterator over the padding types making up a padding region.
+///
+/// This `struct` is created by [`decompose`].
+#[derive(Clone, Debug)]
+pub struct Decompose {
+    remaining: usize,
+}
+
+impl Iterator for Decompose {
+    type Item = AnyPad;
+
+    fn next(&mut self) -> Option<AnyPad> {
+        let pad = match self.remaining {
+            0 => return None,
+            1 => AnyPad::U8,
+            2..=3 => AnyPad::U16,
+            4..=7 => AnyPad::U32,
+            _ => AnyPad::U64,
+        };
+        self.remaining -= pad.size();
+        Some(pad)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = decompose_len(self.remaining);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Decompose {}
+
+impl FusedIterator for Decompose {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_builtin_pad_size;
+
+    #[test]
+    fn len() {
+        assert_eq!(decompose_len(0), 0);
+        assert_eq!(decompose_len(1), 1);
+        assert_eq!(decompose_len(7), 3);
+        assert_eq!(decompose_len(8), 1);
+        assert_eq!(decompose_len(13), 3);
+        assert_eq!(decompose_len(64), 8);
+        for size in 0..100 {
+            assert_eq!(decompose_len(size), decompose(size).count());
+        }
+        const LEN: usize = decompose_len(15);
+        let buffer = [AnyPad::U0; LEN];
+        assert_eq!(buffer.len(), 4);
+    }
+
+    #[test]
+    fn into_buffer() {
+        let mut buffer = [AnyPad::U0; 8];
+        assert_eq!(decompose_into(13, &mut buffer), 3);
+        assert_eq!(buffer[..3], [AnyPad::U64, AnyPad::U32, AnyPad::U8]);
+        assert_eq!(buffer[3..], [AnyPad::U0; 5]);
+
+        let mut exact = [AnyPad::U0; 3];
+        assert_eq!(decompose_into(13, &mut exact), 3);
+        assert_eq!(exact, [AnyPad::U64, AnyPad::U32, AnyPad::U8]);
+
+        let mut small = [AnyPad::U0; 2];
+        assert_eq!(decompose_into(13, &mut small), 3);
+        assert_eq!(small, [AnyPad::U0; 2]);
+
+        assert_eq!(decompose_into(0, &mut []), 0);
+        for size in 0..100 {
+            let mut buffer = [AnyPad::U0; 16];
+            let len = decompose_into(size, &mut buffer);
+            assert!(buffer[..len].iter().copied().eq(decompose(size)));
+        }
+    }
+
+    #[test]
+    fn index() {
+        for (i, &pad) in BUILTIN_PADS.iter().enumerate() {
+            assert_eq!(pad.index(), i);
+            assert_eq!(AnyPad::from_index(i), Some(pad));
+        }
+        assert_eq!(AnyPad::U0.index(), 0);
+        assert_eq!(AnyPad::U64.index(), 4);
+        assert_eq!(AnyPad::from_index(BUILTIN_PAD_COUNT), None);
+        assert_eq!(AnyPad::from_index(usize::MAX), None);
+    }
+
+    #[test]
+    fn sizes_mask() {
+        assert_eq!(AVAILABLE_PAD_SIZES_MASK, 0b1_0001_0111);
+        for n in 0..64 {
+            let available = AVAILABLE_PAD_SIZES_MASK & (1 << n) != 0;
+            assert_eq!(available, is_builtin_pad_size(n));
+        }
+    }
+
+    #[test]
+    fn nearest_size() {
+        assert_eq!(nearest_available_size(0), Some(0));
+        assert_eq!(nearest_available_size(1), Some(1));
+        assert_eq!(nearest_available_size(2), Some(2));
+        assert_eq!(nearest_available_size(3), Some(4));
+        assert_eq!(nearest_available_size(5), Some(8));
+        assert_eq!(nearest_available_size(8), Some(8));
+        assert_eq!(nearest_available_size(9), None);
+        assert_eq!(nearest_available_size(64), None);
+        assert_eq!(nearest_available_size(usize::MAX), None);
+    }
+
+    #[test]
+    fn builtin_pads() {
+        assert_eq!(BUILTIN_PAD_COUNT, 5);
+        assert_eq!(BUILTIN_PADS.len(), BUILTIN_PAD_COUNT);
+        let sizes: [usize; BUILTIN_PAD_COUNT] = [0, 1, 2, 4, 8];
+        for (pad, size) in BUILTIN_PADS.iter().zip(sizes.iter()) {
+            assert_eq!(pad.size(), *size);
+            assert!(is_builtin_pad_size(pad.size()));
+        }
+    }
+
+    #[test]
+    fn by_name() {
+        assert_eq!(pad_by_name("PadU0"), Some(AnyPad::U0));
+        assert_eq!(pad_by_name("PadU8"), Some(AnyPad::U8));
+        assert_eq!(pad_by_name("PadU16"), Some(AnyPad::U16));
+        assert_eq!(pad_by_name("PadU32"), Some(AnyPad::U32));
+        assert_eq!(pad_by_name("PadU64"), Some(AnyPad::U64));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(pad_by_name("PadUsize"), Some(AnyPad::U64));
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(AnyPad::U0.size(), size_of::<PadU0>());
+        assert_eq!(AnyPad::U8.size(), size_of::<PadU8>());
+        assert_eq!(AnyPad::U16.size(), size_of::<PadU16>());
+        assert_eq!(AnyPad::U32.size(), size_of::<PadU32>());
+        assert_eq!(AnyPad::U64.size(), size_of::<PadU64>());
+    }
+
+    #[test]
+    fn pointer_sized() {
+        let pointer_sized = BUILTIN_PADS.iter().filter(|pad| pad.is_pointer_sized());
+        assert_eq!(pointer_sized.count(), 1);
+        assert!(!AnyPad::U0.is_pointer_sized());
+        #[cfg(target_pointer_width = "16")]
+        assert!(AnyPad::U16.is_pointer_sized());
+        #[cfg(target_pointer_width = "32")]
+        assert!(AnyPad::U32.is_pointer_sized());
+        #[cfg(target_pointer_width = "64")]
+        assert!(AnyPad::U64.is_pointer_sized());
+        #[cfg(target_pointer_width = "64")]
+        assert!(!AnyPad::U32.is_pointer_sized());
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(PadU0::try_from(AnyPad::U0), Ok(PadU0::VALUE));
+        assert_eq!(PadU8::try_from(AnyPad::U8), Ok(PadU8::VALUE));
+        assert_eq!(PadU16::try_from(AnyPad::U16), Ok(PadU16::VALUE));
+        assert_eq!(PadU32::try_from(AnyPad::U32), Ok(PadU32::VALUE));
+        assert_eq!(PadU64::try_from(AnyPad::U64), Ok(PadU64::VALUE));
+        assert_eq!(AnyPad::from(PadU32::VALUE), AnyPad::U32);
+    }
+
+    #[test]
+    fn try_from_mismatch() {
+        let err = PadU32::try_from(AnyPad::U16).unwrap_err();
+        assert_eq!(err.expected(), 4);
+        assert_eq!(err.actual(), 2);
+        assert_eq!(PadU0::try_from(AnyPad::U8).unwrap_err().actual(), 1);
+        assert_eq!(PadU64::try_from(AnyPad::U0).unwrap_err().expected(), 8);
+    }
+
+    #[test]
+    fn decompose_sizes() {
+        for size in 0..100 {
+            let pads = decompose(size);
+            let len = pads.len();
+            assert_eq!(pads.clone().count(), len);
+            assert_eq!(pads.map(AnyPad::size).sum::<usize>(), size);
+        }
+    }
+
+    #[test]
+    fn decompose_order() {
+        let mut pads = decompose(23);
+        assert_eq!(pads.next(), Some(AnyPad::U64));
+        assert_eq!(pads.next(), Some(AnyPad::U64));
+        assert_eq!(pads.next(), Some(AnyPad::U32));
+        assert_eq!(pads.next(), Some(AnyPad::U16));
+        assert_eq!(pads.next(), Some(AnyPad::U8));
+        assert_eq!(pads.next(), None);
+        assert_eq!(decompose(0).next(), None);
+    }
+
+    #[test]
+    fn by_name_unknown() {
+        assert_eq!(pad_by_name(""), None);
+        assert_eq!(pad_by_name("PadU128"), None);
+        assert_eq!(pad_by_name("padu32"), None);
+        assert_eq!(pad_by_name("U32"), None);
+    }
+}
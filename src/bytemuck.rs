@@ -0,0 +1,96 @@
+// Padding types are deliberately not `Pod` or `AnyBitPattern`: they have
+// only one valid bit-pattern, so casting arbitrary bytes into a pad would
+// be unsound. They are, however, `Zeroable` (all zeros is that one valid
+// bit-pattern) and `NoUninit` (they contain no uninitialized bytes), which
+// is enough to view structs containing pads as bytes.
+
+use ::bytemuck::{NoUninit, Zeroable};
+
+use crate::{
+    DontCarePad, Pad, PadBytes, PadFlexible, PadMarker, PadSimd128, PadSimd128Bytes, PadU0, PadU16,
+    PadU32, PadU64, PadU8, PadWord, PadWords, ZeroedPad,
+};
+
+macro_rules! impl_bytemuck {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: the all-zeros bit-pattern is the only valid value.
+            unsafe impl Zeroable for $ty {}
+
+            // SAFETY: padding types are made up of initialized zero bytes,
+            // with no interior padding.
+            unsafe impl NoUninit for $ty {}
+        )*
+    };
+}
+
+impl_bytemuck!(
+    PadU0,
+    PadFlexible,
+    PadU8,
+    PadU16,
+    PadU32,
+    PadU64,
+    PadSimd128,
+    PadSimd128Bytes,
+    PadWord
+);
+
+// SAFETY: the all-zeros bit-pattern is the only valid value.
+unsafe impl<const N: usize> Zeroable for PadBytes<N> {}
+
+// SAFETY: `PadBytes<N>` is an array of `PadU8`, each an initialized byte.
+unsafe impl<const N: usize> NoUninit for PadBytes<N> {}
+
+// SAFETY: the all-zeros bit-pattern is the only valid value.
+unsafe impl<const N: usize> Zeroable for PadWords<N> {}
+
+// SAFETY: `PadWords<N>` is an array of `PadWord`, with no interior padding.
+unsafe impl<const N: usize> NoUninit for PadWords<N> {}
+
+// SAFETY: `PadMarker<T>` is zero-sized, whatever `T` is.
+unsafe impl<T: ?Sized> Zeroable for PadMarker<T> {}
+unsafe impl<T: ?Sized + 'static> NoUninit for PadMarker<T> {}
+
+// SAFETY: the wrappers are `repr(transparent)` over the wrapped pad.
+unsafe impl<P: Pad + Zeroable> Zeroable for ZeroedPad<P> {}
+unsafe impl<P: Pad + NoUninit> NoUninit for ZeroedPad<P> {}
+unsafe impl<P: Pad + Zeroable> Zeroable for DontCarePad<P> {}
+unsafe impl<P: Pad + NoUninit> NoUninit for DontCarePad<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pad;
+
+    #[derive(Clone, Copy, ::bytemuck::NoUninit)]
+    #[repr(C)]
+    struct Record {
+        tag: u8,
+        reserved: PadBytes<5>,
+        len: u16,
+    }
+
+    #[test]
+    fn bytes_of() {
+        let record = Record {
+            tag: 1,
+            reserved: Pad::VALUE,
+            len: 2,
+        };
+        let bytes = ::bytemuck::bytes_of(&record);
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[1..6], [0; 5]);
+        assert_eq!(u16::from_ne_bytes([bytes[6], bytes[7]]), 2);
+    }
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(<PadU32 as Zeroable>::zeroed(), PadU32::VALUE);
+        assert_eq!(<PadBytes<5> as Zeroable>::zeroed(), PadBytes::VALUE);
+        assert_eq!(::bytemuck::bytes_of(&PadSimd128Bytes::VALUE), [0; 16]);
+        assert_eq!(<ZeroedPad<PadU64> as Zeroable>::zeroed(), ZeroedPad::VALUE);
+        assert_eq!(::bytemuck::bytes_of(&DontCarePad::<PadU16>::VALUE), [0; 2]);
+    }
+}
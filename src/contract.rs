@@ -0,0 +1,172 @@
+//! Padding wrappers stating the validation contract of a padding field.
+//!
+//! Both wrappers have the same layout as the padding type they wrap, and
+//! hold its only valid value. They differ only in what is expected of the
+//! bytes received in their place, for instance from foreign code.
+
+use core::ops::Index;
+
+use crate::{index_zero, Pad};
+
+/// A padding field whose bytes must be zero.
+///
+/// [`Pad::assert_clean`] checks the bytes received in place of a
+/// `ZeroedPad`, so protocol bugs that leave garbage in reserved fields are
+/// caught in debug builds.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{Pad, PadU32, ZeroedPad};
+///
+/// let pad = ZeroedPad::<PadU32>::VALUE;
+/// pad.assert_clean(&[0; 4]);
+/// ```
+#[derive(Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct ZeroedPad<P>(P);
+
+/// A padding field whose bytes are irrelevant.
+///
+/// The bytes received in place of a `DontCarePad` may hold anything, so
+/// [`Pad::assert_clean`] only checks that there are as many of them as the
+/// wrapped padding type occupies. The Rust value is still the only valid
+/// value of the wrapped padding type.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{DontCarePad, Pad, PadU32};
+///
+/// let pad = DontCarePad::<PadU32>::VALUE;
+/// pad.assert_clean(&[0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[derive(Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct DontCarePad<P>(P);
+
+// `Clone` is implemented by hand, so that the wrappers forward `clone_from`
+// to the wrapped padding type.
+impl<P: Clone> Clone for ZeroedPad<P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl<P: Clone> Clone for DontCarePad<P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl<P: Pad> Index<usize> for ZeroedPad<P> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl<P: Pad> Index<usize> for DontCarePad<P> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl<P: Pad> Pad for ZeroedPad<P> {
+    const VALUE: Self = Self(P::VALUE);
+    const HAS_NICHE: bool = P::HAS_NICHE;
+
+    #[inline]
+    fn bytes(&self) -> &'static [u8] {
+        self.0.bytes()
+    }
+
+    #[inline]
+    fn assert_clean(&self, bytes: &[u8]) {
+        self.0.assert_clean(bytes);
+    }
+}
+
+impl<P: Pad> Pad for DontCarePad<P> {
+    const VALUE: Self = Self(P::VALUE);
+    const HAS_NICHE: bool = P::HAS_NICHE;
+
+    #[inline]
+    fn bytes(&self) -> &'static [u8] {
+        self.0.bytes()
+    }
+
+    #[inline]
+    fn assert_clean(&self, bytes: &[u8]) {
+        debug_assert_eq!(bytes.len(), Self::SIZE, "padding has the wrong size");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PadBytes, PadU32, PadU64};
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<ZeroedPad<PadU32>>(), 4);
+        assert_eq!(align_of::<ZeroedPad<PadU64>>(), align_of::<PadU64>());
+        assert_eq!(size_of::<DontCarePad<PadBytes<12>>>(), 12);
+        assert_eq!(size_of::<Option<ZeroedPad<PadU32>>>(), 4);
+        assert_eq!(ZeroedPad::<PadBytes<12>>::VALUE.bytes(), [0; 12]);
+        assert_eq!(DontCarePad::<PadU32>::VALUE[3], 0);
+    }
+
+    #[test]
+    fn clone() {
+        let mut zeroed = ZeroedPad::<PadU32>::VALUE;
+        zeroed.clone_from(&ZeroedPad::VALUE);
+        assert_eq!(zeroed.clone(), ZeroedPad::VALUE);
+        let mut dont_care = DontCarePad::<PadBytes<3>>::VALUE;
+        dont_care.clone_from(&DontCarePad::VALUE);
+        assert_eq!(dont_care.clone(), DontCarePad::VALUE);
+    }
+
+    #[test]
+    fn zeroed_clean() {
+        ZeroedPad::<PadU32>::VALUE.assert_clean(&[0; 4]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "padding must be zero")]
+    fn zeroed_dirty() {
+        ZeroedPad::<PadU32>::VALUE.assert_clean(&[0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn dont_care() {
+        DontCarePad::<PadU32>::VALUE.assert_clean(&[0; 4]);
+        DontCarePad::<PadU32>::VALUE.assert_clean(&[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "padding has the wrong size")]
+    fn dont_care_wrong_size() {
+        DontCarePad::<PadU32>::VALUE.assert_clean(&[1]);
+    }
+}
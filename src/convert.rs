@@ -0,0 +1,205 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{Pad, PadBytes, PadU16, PadU32, PadU64, PadU8, PadUsize};
+
+/// The error returned when converting a nonzero integer into a padding type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NonZeroPadError(());
+
+impl fmt::Display for NonZeroPadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("padding must be zero")
+    }
+}
+
+impl TryFrom<u8> for PadU8 {
+    type Error = NonZeroPadError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::VALUE),
+            _ => Err(NonZeroPadError(())),
+        }
+    }
+}
+
+impl TryFrom<u16> for PadU16 {
+    type Error = NonZeroPadError;
+
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::VALUE),
+            _ => Err(NonZeroPadError(())),
+        }
+    }
+}
+
+impl TryFrom<u32> for PadU32 {
+    type Error = NonZeroPadError;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::VALUE),
+            _ => Err(NonZeroPadError(())),
+        }
+    }
+}
+
+impl TryFrom<u64> for PadU64 {
+    type Error = NonZeroPadError;
+
+    #[inline]
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::VALUE),
+            _ => Err(NonZeroPadError(())),
+        }
+    }
+}
+
+impl TryFrom<usize> for PadUsize {
+    type Error = NonZeroPadError;
+
+    #[inline]
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::VALUE),
+            _ => Err(NonZeroPadError(())),
+        }
+    }
+}
+
+// Both families hold only zero bytes, so converting between pads of the
+// same size cannot fail. `TryFrom` is covered by the blanket impl over
+// `From`.
+macro_rules! impl_from_bytes {
+    ($($ty:ty => $n:literal),*) => {
+        $(
+            impl From<PadBytes<$n>> for $ty {
+                #[inline]
+                fn from(_: PadBytes<$n>) -> Self {
+                    Self::VALUE
+                }
+            }
+
+            impl From<$ty> for PadBytes<$n> {
+                #[inline]
+                fn from(_: $ty) -> Self {
+                    Self::VALUE
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bytes!(PadU8 => 1, PadU16 => 2, PadU32 => 4, PadU64 => 8);
+
+// The conversions to and from `MaybeUninit` are sound without `unsafe`:
+// converting into one wraps initialized zero bytes, and converting back
+// never reads the (possibly uninitialized) contents, returning the only
+// valid value instead.
+macro_rules! impl_maybe_uninit {
+    ($($ty:ty => $n:literal),*) => {
+        $(
+            impl $ty {
+                /// Returns the zero bytes of `self`, for reuse as a buffer.
+                #[inline]
+                #[must_use]
+                pub const fn into_maybe_uninit(self) -> MaybeUninit<[u8; $n]> {
+                    MaybeUninit::new([0; $n])
+                }
+
+                /// Returns the only valid value of this padding type.
+                ///
+                /// The contents of the buffer are never read, so it may be
+                /// uninitialized.
+                #[inline]
+                #[must_use]
+                pub const fn from_maybe_uninit(_: MaybeUninit<[u8; $n]>) -> Self {
+                    Self::VALUE
+                }
+            }
+        )*
+    };
+}
+
+impl_maybe_uninit!(PadU8 => 1, PadU16 => 2, PadU32 => 4, PadU64 => 8);
+
+impl<const N: usize> PadBytes<N> {
+    /// Returns the zero bytes of `self`, for reuse as a buffer.
+    #[inline]
+    #[must_use]
+    pub const fn into_maybe_uninit(self) -> MaybeUninit<[u8; N]> {
+        MaybeUninit::new([0; N])
+    }
+
+    /// Returns the only valid value of this padding type.
+    ///
+    /// The contents of the buffer are never read, so it may be
+    /// uninitialized.
+    #[inline]
+    #[must_use]
+    pub const fn from_maybe_uninit(_: MaybeUninit<[u8; N]>) -> Self {
+        Self::VALUE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_zero() {
+        assert_eq!(PadU8::try_from(0u8), Ok(PadU8::VALUE));
+        assert_eq!(PadU16::try_from(0u16), Ok(PadU16::VALUE));
+        assert_eq!(PadU32::try_from(0u32), Ok(PadU32::VALUE));
+        assert_eq!(PadU64::try_from(0u64), Ok(PadU64::VALUE));
+        assert_eq!(PadUsize::try_from(0usize), Ok(PadUsize::VALUE));
+    }
+
+    #[test]
+    fn try_from_nonzero() {
+        assert!(PadU8::try_from(1u8).is_err());
+        assert!(PadU16::try_from(0x100u16).is_err());
+        assert!(PadU32::try_from(u32::MAX).is_err());
+        assert!(PadU64::try_from(1u64 << 63).is_err());
+        assert!(PadUsize::try_from(1usize).is_err());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let bytes: PadBytes<4> = PadU32::VALUE.into();
+        assert_eq!(bytes, PadBytes::VALUE);
+        assert_eq!(PadU32::from(bytes), PadU32::VALUE);
+        assert_eq!(PadU8::from(PadBytes::<1>::VALUE), PadU8::VALUE);
+        assert_eq!(PadU16::from(PadBytes::<2>::VALUE), PadU16::VALUE);
+        assert_eq!(PadU64::from(PadBytes::<8>::VALUE), PadU64::VALUE);
+        assert_eq!(PadBytes::<8>::from(PadU64::VALUE), PadBytes::VALUE);
+    }
+
+    #[test]
+    fn maybe_uninit_round_trip() {
+        let buffer = PadU32::VALUE.into_maybe_uninit();
+        // SAFETY: `into_maybe_uninit` initializes the buffer.
+        assert_eq!(unsafe { buffer.assume_init() }, [0; 4]);
+        assert_eq!(PadU32::from_maybe_uninit(buffer), PadU32::VALUE);
+        assert_eq!(
+            PadU32::from_maybe_uninit(MaybeUninit::uninit()),
+            PadU32::VALUE
+        );
+        assert_eq!(
+            PadBytes::<3>::from_maybe_uninit(PadBytes::<3>::VALUE.into_maybe_uninit()),
+            PadBytes::VALUE
+        );
+        // SAFETY: as above.
+        assert_eq!(
+            unsafe { PadU8::VALUE.into_maybe_uninit().assume_init() },
+            [0]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    DontCarePad, Pad, PadBytes, PadFlexible, PadMarker, PadSimd128, PadSimd128Bytes, PadU0, PadU16,
+    PadU32, PadU64, PadU8, PadWord, PadWords, ZeroedPad,
+};
+
+/// A padding type whose bytes may be bit-copied onto another padding field.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that copying the bytes of a value of
+/// `Self` onto any location holding a padding type of the same size, for
+/// instance with [`ptr::copy_nonoverlapping`](core::ptr::copy_nonoverlapping),
+/// leaves a valid value at that location. This holds for padding types
+/// whose only valid bit-pattern is all zeros, such as every padding type of
+/// this crate.
+///
+/// The sizes must be equal: copying the bytes of a pad onto a larger one
+/// leaves the remaining bytes of the destination untouched, and copying
+/// onto a smaller one writes past its end.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::size_of;
+/// use core::ptr;
+/// use struct_pad::{BitCopyablePad, Pad, PadBytes, PadU32};
+///
+/// fn copy_pad<A: BitCopyablePad, B: BitCopyablePad>(src: &A, dst: &mut B) {
+///     assert_eq!(size_of::<A>(), size_of::<B>());
+///     // SAFETY: both pads are bit-copyable and of equal size.
+///     unsafe {
+///         ptr::copy_nonoverlapping(
+///             src as *const A as *const u8,
+///             dst as *mut B as *mut u8,
+///             size_of::<A>(),
+///         );
+///     }
+/// }
+///
+/// let mut dst = PadBytes::<4>::VALUE;
+/// copy_pad(&PadU32::VALUE, &mut dst);
+/// assert_eq!(dst, PadBytes::VALUE);
+/// ```
+pub unsafe trait BitCopyablePad: Pad {}
+
+// SAFETY: the only valid bit-pattern of each of these types is all zeros.
+unsafe impl BitCopyablePad for PadU0 {}
+unsafe impl BitCopyablePad for PadFlexible {}
+unsafe impl<T: ?Sized> BitCopyablePad for PadMarker<T> {}
+unsafe impl BitCopyablePad for PadU8 {}
+unsafe impl BitCopyablePad for PadU16 {}
+unsafe impl BitCopyablePad for PadU32 {}
+unsafe impl BitCopyablePad for PadU64 {}
+unsafe impl<const N: usize> BitCopyablePad for PadBytes<N> {}
+unsafe impl BitCopyablePad for PadSimd128 {}
+unsafe impl BitCopyablePad for PadSimd128Bytes {}
+unsafe impl BitCopyablePad for PadWord {}
+unsafe impl<const N: usize> BitCopyablePad for PadWords<N> {}
+
+// SAFETY: the wrappers are `repr(transparent)` over the wrapped pad.
+unsafe impl<P: BitCopyablePad> BitCopyablePad for ZeroedPad<P> {}
+unsafe impl<P: BitCopyablePad> BitCopyablePad for DontCarePad<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{size_of, MaybeUninit};
+    use core::ptr;
+
+    fn copy<A: BitCopyablePad, B: BitCopyablePad>(src: &A) -> B {
+        assert_eq!(size_of::<A>(), size_of::<B>());
+        let mut dst = MaybeUninit::<B>::uninit();
+        // SAFETY: `B` is bit-copyable and of the same size as `A`, so the
+        // copy initializes `dst` with a valid value.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                src as *const A as *const u8,
+                dst.as_mut_ptr() as *mut u8,
+                size_of::<A>(),
+            );
+            dst.assume_init()
+        }
+    }
+
+    #[test]
+    fn bit_copy() {
+        assert_eq!(copy::<PadU32, PadU32>(&PadU32::VALUE), PadU32::VALUE);
+        assert_eq!(copy::<PadU32, PadBytes<4>>(&PadU32::VALUE), PadBytes::VALUE);
+        assert_eq!(copy::<PadU64, PadWord>(&PadU64::VALUE), PadWord::VALUE);
+        assert_eq!(
+            copy::<PadSimd128Bytes, PadWords<2>>(&PadSimd128Bytes::VALUE),
+            PadWords::VALUE
+        );
+        assert_eq!(
+            copy::<PadU0, PadMarker<str>>(&PadU0::VALUE),
+            PadMarker::VALUE
+        );
+        assert_eq!(
+            copy::<ZeroedPad<PadU16>, DontCarePad<PadU16>>(&ZeroedPad::VALUE),
+            DontCarePad::VALUE
+        );
+    }
+}
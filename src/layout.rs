@@ -0,0 +1,1312 @@
+//! Layout arithmetic.
+//!
+//! Field descriptors are `(size, align)` pairs, laid out in order
+//! following the rules of `#[repr(C)]`. Alignments are expected to be
+//! nonzero powers of two.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Returns the number of padding bytes needed to round `offset`
+/// up to a multiple of `align`.
+#[inline]
+const fn padding_for(offset: usize, align: usize) -> usize {
+    (align - offset % align) % align
+}
+
+// Lays out `fields` in order, returning the offset just past the last
+// field, the alignment of the struct, and the padding between fields.
+const fn lay_out(fields: &[(usize, usize)]) -> (usize, usize, usize) {
+    let mut offset = 0;
+    let mut align = 1;
+    let mut padding = 0;
+    let mut i = 0;
+    while i < fields.len() {
+        let (field_size, field_align) = fields[i];
+        let gap = padding_for(offset, field_align);
+        padding += gap;
+        offset += gap + field_size;
+        if field_align > align {
+            align = field_align;
+        }
+        i += 1;
+    }
+    (offset, align, padding)
+}
+
+// Unwraps an `Option` in a `const fn`, returning `None` from the caller.
+macro_rules! try_opt {
+    ($e:expr) => {
+        match $e {
+            Some(value) => value,
+            None => return None,
+        }
+    };
+}
+
+// Like `padding_for`, but returns `None` unless `align` is a power of two.
+const fn checked_padding_for(offset: usize, align: usize) -> Option<usize> {
+    if align.is_power_of_two() {
+        Some(padding_for(offset, align))
+    } else {
+        None
+    }
+}
+
+// Like `lay_out`, but returns `None` on overflow, or if an alignment is not
+// a power of two.
+const fn checked_lay_out(fields: &[(usize, usize)]) -> Option<(usize, usize, usize)> {
+    let mut offset: usize = 0;
+    let mut align = 1;
+    let mut padding: usize = 0;
+    let mut i = 0;
+    while i < fields.len() {
+        let (field_size, field_align) = fields[i];
+        let gap = try_opt!(checked_padding_for(offset, field_align));
+        padding = try_opt!(padding.checked_add(gap));
+        offset = try_opt!(offset.checked_add(gap));
+        offset = try_opt!(offset.checked_add(field_size));
+        if field_align > align {
+            align = field_align;
+        }
+        i += 1;
+    }
+    Some((offset, align, padding))
+}
+
+/// Returns the total padding `#[repr(C)]` would insert between and after
+/// the given fields.
+///
+/// This includes any trailing padding needed to round the size of the
+/// struct up to a multiple of its alignment.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::total_padding;
+///
+/// // struct { a: u8, b: u64 }
+/// assert_eq!(total_padding(&[(1, 1), (8, 8)]), 7);
+/// ```
+#[must_use]
+pub const fn total_padding(fields: &[(usize, usize)]) -> usize {
+    let (end, align, padding) = lay_out(fields);
+    padding + padding_for(end, align)
+}
+
+/// Returns the trailing padding `#[repr(C)]` would insert after the last
+/// of the given fields, to round the size of the struct up to a multiple of
+/// its alignment.
+///
+/// This is the part of [`total_padding`] which follows the fields, rather
+/// than separating them, and which reordering the fields cannot always
+/// reclaim. See [`checked_tail_padding`] for a variant which returns `None`
+/// if the layout overflows a `usize`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::tail_padding;
+///
+/// // struct { a: u64, b: u8 }
+/// assert_eq!(tail_padding(&[(8, 8), (1, 1)]), 7);
+/// // struct { a: u8, b: u32 }
+/// assert_eq!(tail_padding(&[(1, 1), (4, 4)]), 0);
+/// ```
+#[must_use]
+pub const fn tail_padding(fields: &[(usize, usize)]) -> usize {
+    let (end, align, _) = lay_out(fields);
+    padding_for(end, align)
+}
+
+/// Returns the offset of the field at index `n` in a `#[repr(C)]` struct
+/// with the given fields.
+///
+/// The offset accounts for any padding inserted before the field to
+/// align it. See [`checked_field_offset`] for a variant which returns
+/// `None` instead of panicking.
+///
+/// # Panics
+///
+/// Panics if `n` is out of range, or, in debug builds, if the offset
+/// overflows a `usize`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::field_offset;
+///
+/// // struct { a: u8, b: u64 }
+/// assert_eq!(field_offset(&[(1, 1), (8, 8)], 1), 8);
+/// ```
+#[must_use]
+pub const fn field_offset(fields: &[(usize, usize)], n: usize) -> usize {
+    assert!(n < fields.len(), "field index out of range");
+    let mut offset = 0;
+    let mut i = 0;
+    while i < n {
+        let (field_size, field_align) = fields[i];
+        offset += padding_for(offset, field_align) + field_size;
+        i += 1;
+    }
+    offset + padding_for(offset, fields[n].1)
+}
+
+/// Returns the size of a `#[repr(C)]` struct with the given fields.
+///
+/// This includes any trailing padding needed to round the size of the
+/// struct up to a multiple of its alignment.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::struct_size;
+///
+/// // struct { a: u8, b: u32 }
+/// const SIZE: usize = struct_size(&[(1, 1), (4, 4)]);
+/// assert_eq!(SIZE, 8);
+/// ```
+#[must_use]
+pub const fn struct_size(fields: &[(usize, usize)]) -> usize {
+    let (end, align, _) = lay_out(fields);
+    end + padding_for(end, align)
+}
+
+/// Returns the size of a `#[repr(C)]` struct with the given fields,
+/// in the given order.
+///
+/// This is [`struct_size`], named for comparing orderings of the same
+/// fields: since `#[repr(C)]` does not reorder fields, trying permutations
+/// and keeping the smallest quantifies the benefit of reordering.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::size_of_ordering;
+///
+/// assert_eq!(size_of_ordering(&[(1, 1), (8, 8), (1, 1)]), 24);
+/// assert_eq!(size_of_ordering(&[(8, 8), (1, 1), (1, 1)]), 16);
+/// ```
+#[inline]
+#[must_use]
+pub const fn size_of_ordering(fields: &[(usize, usize)]) -> usize {
+    struct_size(fields)
+}
+
+/// Returns the number of bytes saved by changing a layout from `old` bytes
+/// to `new` bytes.
+///
+/// The result is positive if the new layout is smaller, and negative if it
+/// is larger.
+///
+/// # Overflow
+///
+/// The subtraction wraps, so the result is only meaningful if both sizes
+/// are at most `isize::MAX`, which holds for the size of every Rust type.
+/// See [`checked_layout_savings`] for a variant which returns `None`
+/// otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{layout_savings, size_of_ordering};
+///
+/// let old = size_of_ordering(&[(1, 1), (8, 8), (1, 1)]);
+/// let new = size_of_ordering(&[(8, 8), (1, 1), (1, 1)]);
+/// assert_eq!(layout_savings(old, new), 8);
+/// ```
+#[inline]
+#[must_use]
+pub const fn layout_savings(old: usize, new: usize) -> isize {
+    (old as isize).wrapping_sub(new as isize)
+}
+
+/// Returns the number of bytes a union of variants with the given sizes
+/// needs to reserve, i.e. the largest size.
+///
+/// This is intended for emulating a C union with a reserved byte region,
+/// sized with [`PadBytes`](crate::PadBytes). The region has an alignment
+/// of 1, so any alignment the union needs must be provided separately.
+/// The result is one of the given sizes, so it never overflows.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{union_reserve, PadBytes};
+///
+/// #[repr(C)]
+/// struct Tagged {
+///     tag: u32,
+///     payload: PadBytes<{ union_reserve(&[4, 12, 8]) }>,
+/// }
+///
+/// assert_eq!(core::mem::size_of::<Tagged>(), 16);
+/// ```
+#[must_use]
+pub const fn union_reserve(variant_sizes: &[usize]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < variant_sizes.len() {
+        if variant_sizes[i] > max {
+            max = variant_sizes[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+/// The largest number of fields [`optimal_ordering`] accepts.
+///
+/// The search tries every ordering, so its cost grows with the factorial
+/// of the number of fields; 8 fields make for 40320 orderings.
+#[cfg(feature = "alloc")]
+pub const MAX_ORDERING_FIELDS: usize = 8;
+
+/// Returns the names of the given fields, in the order minimizing the size
+/// of a `#[repr(C)]` struct.
+///
+/// Each field is a `(size, align, name)` triple. Every ordering is tried,
+/// in lexicographic order of the field indices, and the first one with the
+/// smallest size is returned; in particular, the given order is kept if it
+/// is already optimal. Padding fields may then be added to the reordered
+/// struct to make its remaining padding explicit.
+///
+/// # Panics
+///
+/// Panics if more than [`MAX_ORDERING_FIELDS`] fields are given.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::optimal_ordering;
+///
+/// let fields = [(1, 1, "a"), (8, 8, "b"), (2, 2, "c")];
+/// assert_eq!(optimal_ordering(&fields), ["a", "c", "b"]);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn optimal_ordering<'a>(fields: &[(usize, usize, &'a str)]) -> Vec<&'a str> {
+    assert!(
+        fields.len() <= MAX_ORDERING_FIELDS,
+        "cannot search the orderings of more than {} fields",
+        MAX_ORDERING_FIELDS
+    );
+    let mut order: Vec<usize> = (0..fields.len()).collect();
+    let mut best = order.clone();
+    let mut best_size = usize::MAX;
+    let mut layout = Vec::with_capacity(fields.len());
+    loop {
+        layout.clear();
+        layout.extend(order.iter().map(|&i| (fields[i].0, fields[i].1)));
+        let size = struct_size(&layout);
+        if size < best_size {
+            best_size = size;
+            best.clone_from(&order);
+        }
+        if !next_permutation(&mut order) {
+            break;
+        }
+    }
+    best.iter().map(|&i| fields[i].2).collect()
+}
+
+// Advances `order` to the next permutation in lexicographic order,
+// returning `false` once every permutation has been visited.
+#[cfg(feature = "alloc")]
+fn next_permutation(order: &mut [usize]) -> bool {
+    let pivot = match order.windows(2).rposition(|pair| pair[0] < pair[1]) {
+        Some(pivot) => pivot,
+        None => return false,
+    };
+    let pivot_value = order[pivot];
+    let successor = pivot
+        + 1
+        + order[pivot + 1..]
+            .iter()
+            .rposition(|&index| index > pivot_value)
+            .unwrap_or(0);
+    order.swap(pivot, successor);
+    order[pivot + 1..].reverse();
+    true
+}
+
+/// Returns `true` if a `#[repr(C)]` struct with the given fields has a size
+/// of `total` bytes, none of which are padding.
+///
+/// A struct which is not tightly packed either has a different size than
+/// declared, or has implicit padding which explicit padding fields could
+/// reclaim.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::is_tightly_packed;
+///
+/// // struct { a: u32, b: u16, c: u8, d: u8 }
+/// assert!(is_tightly_packed(&[(4, 4), (2, 2), (1, 1), (1, 1)], 8));
+/// // struct { a: u32, b: u8 }
+/// assert!(!is_tightly_packed(&[(4, 4), (1, 1)], 8));
+/// ```
+#[must_use]
+pub const fn is_tightly_packed(fields: &[(usize, usize)], total: usize) -> bool {
+    let (end, align, padding) = lay_out(fields);
+    padding == 0 && padding_for(end, align) == 0 && end == total
+}
+
+/// Returns `true` if laying out the given pads in order, as `#[repr(C)]`
+/// would, introduces no gaps between them.
+///
+/// Unlike [`is_tightly_packed`], trailing padding is not considered, since
+/// a composite padding region is usually followed by further fields. A
+/// region which is not contiguous needs additional pads to fill its gaps.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::is_contiguous;
+///
+/// // PadU32, PadU16, PadU8
+/// assert!(is_contiguous(&[(4, 4), (2, 2), (1, 1)]));
+/// // PadU8, PadU32
+/// assert!(!is_contiguous(&[(1, 1), (4, 4)]));
+/// ```
+#[must_use]
+pub const fn is_contiguous(pads: &[(usize, usize)]) -> bool {
+    let (_, _, padding) = lay_out(pads);
+    padding == 0
+}
+
+/// Like [`total_padding`], but returns `None` if the layout overflows
+/// a `usize`, or if an alignment is not a power of two.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::checked_total_padding;
+///
+/// assert_eq!(checked_total_padding(&[(1, 1), (8, 8)]), Some(7));
+/// assert_eq!(checked_total_padding(&[(usize::MAX, 1), (8, 8)]), None);
+/// ```
+#[must_use]
+pub const fn checked_total_padding(fields: &[(usize, usize)]) -> Option<usize> {
+    let (end, align, padding) = try_opt!(checked_lay_out(fields));
+    let tail = padding_for(end, align);
+    try_opt!(end.checked_add(tail));
+    padding.checked_add(tail)
+}
+
+/// Like [`struct_size`], but returns `None` if the size overflows
+/// a `usize`, or if an alignment is not a power of two.
+///
+/// This is intended for tools processing untrusted layout descriptions.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::checked_struct_size;
+///
+/// assert_eq!(checked_struct_size(&[(1, 1), (4, 4)]), Some(8));
+/// assert_eq!(checked_struct_size(&[(usize::MAX, 1), (1, 1)]), None);
+/// ```
+#[must_use]
+pub const fn checked_struct_size(fields: &[(usize, usize)]) -> Option<usize> {
+    let (end, align, _) = try_opt!(checked_lay_out(fields));
+    end.checked_add(padding_for(end, align))
+}
+
+/// Like [`tail_padding`], but returns `None` if the layout overflows
+/// a `usize`, or if an alignment is not a power of two.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::checked_tail_padding;
+///
+/// assert_eq!(checked_tail_padding(&[(8, 8), (1, 1)]), Some(7));
+/// assert_eq!(checked_tail_padding(&[(usize::MAX, 1), (1, 1)]), None);
+/// ```
+#[must_use]
+pub const fn checked_tail_padding(fields: &[(usize, usize)]) -> Option<usize> {
+    let (end, align, _) = try_opt!(checked_lay_out(fields));
+    let tail = padding_for(end, align);
+    try_opt!(end.checked_add(tail));
+    Some(tail)
+}
+
+/// Like [`field_offset`], but returns `None` if `n` is out of range, if
+/// the offset overflows a `usize`, or if an alignment is not a power of two.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::checked_field_offset;
+///
+/// assert_eq!(checked_field_offset(&[(1, 1), (8, 8)], 1), Some(8));
+/// assert_eq!(checked_field_offset(&[(1, 1), (8, 8)], 2), None);
+/// ```
+#[must_use]
+pub const fn checked_field_offset(fields: &[(usize, usize)], n: usize) -> Option<usize> {
+    if n >= fields.len() {
+        return None;
+    }
+    let mut offset: usize = 0;
+    let mut i = 0;
+    while i < n {
+        let (field_size, field_align) = fields[i];
+        let gap = try_opt!(checked_padding_for(offset, field_align));
+        offset = try_opt!(offset.checked_add(gap));
+        offset = try_opt!(offset.checked_add(field_size));
+        i += 1;
+    }
+    let gap = try_opt!(checked_padding_for(offset, fields[n].1));
+    offset.checked_add(gap)
+}
+
+/// Like [`layout_savings`], but returns `None` if either size is greater
+/// than `isize::MAX`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::checked_layout_savings;
+///
+/// assert_eq!(checked_layout_savings(24, 16), Some(8));
+/// assert_eq!(checked_layout_savings(usize::MAX, 16), None);
+/// ```
+#[inline]
+#[must_use]
+pub const fn checked_layout_savings(old: usize, new: usize) -> Option<isize> {
+    if old > isize::MAX as usize || new > isize::MAX as usize {
+        None
+    } else {
+        Some(old as isize - new as isize)
+    }
+}
+
+/// Returns `true` if a padding field of `pad_size` bytes placed at `offset`
+/// ends at or before `target`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::fits;
+///
+/// assert!(fits(12, 4, 16));
+/// assert!(!fits(12, 8, 16));
+/// ```
+#[inline]
+#[must_use]
+pub const fn fits(offset: usize, pad_size: usize, target: usize) -> bool {
+    match offset.checked_add(pad_size) {
+        Some(end) => end <= target,
+        None => false,
+    }
+}
+
+/// Returns `true` if a padding field of `pad_size` bytes is larger than
+/// the `gap` it is meant to fill.
+///
+/// An overfilling pad silently pushes the following fields to higher
+/// offsets, so this is intended for a constant assertion next to each
+/// padding field.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{pad_overfills, Pad, PadU16};
+///
+/// // struct { a: u16, b: u32 }
+/// const _: () = assert!(!pad_overfills(2, PadU16::SIZE));
+/// assert!(pad_overfills(2, 4));
+/// ```
+#[inline]
+#[must_use]
+pub const fn pad_overfills(gap: usize, pad_size: usize) -> bool {
+    pad_size > gap
+}
+
+/// Returns `true` if `size` is a multiple of a nonzero `align`.
+///
+/// Every Rust type satisfies this, so it is a useful sanity check
+/// for the parameters of hand-written padding types and layouts. It is
+/// checked at compile time for every padding type of this crate.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::is_well_formed;
+///
+/// assert!(is_well_formed(8, 4));
+/// assert!(!is_well_formed(6, 4));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_well_formed(size: usize, align: usize) -> bool {
+    align != 0 && size % align == 0
+}
+
+/// Returns `true` if adding a field with an alignment of `pad_align` to a
+/// struct with an alignment of `current_align` would raise the alignment
+/// of the struct.
+///
+/// This may be used in a constant assertion, to check that a padding type
+/// chosen to fill a gap does not over-align the struct.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::align_of;
+/// use struct_pad::{raises_align, Pad, PadU16};
+///
+/// const _: () = assert!(!raises_align(align_of::<u32>(), PadU16::ALIGN));
+/// assert!(raises_align(4, 8));
+/// ```
+#[inline]
+#[must_use]
+pub const fn raises_align(current_align: usize, pad_align: usize) -> bool {
+    pad_align > current_align
+}
+
+/// Returns the padding to insert at `parent_offset` within a parent struct,
+/// so that a nested child struct with an alignment of `child_align` placed
+/// after it is properly aligned.
+///
+/// # Panics
+///
+/// Panics if `child_align` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::align_nested;
+///
+/// assert_eq!(align_nested(6, 8), 2);
+/// assert_eq!(align_nested(8, 8), 0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn align_nested(parent_offset: usize, child_align: usize) -> usize {
+    padding_for(parent_offset, child_align)
+}
+
+/// Returns the number of cache lines of `line` bytes spanned by a struct
+/// of `size` bytes, assuming the struct starts on a cache line boundary.
+///
+/// # Panics
+///
+/// Panics if `line` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::cache_lines;
+///
+/// assert_eq!(cache_lines(64, 64), 1);
+/// assert_eq!(cache_lines(65, 64), 2);
+/// ```
+#[inline]
+#[must_use]
+pub const fn cache_lines(size: usize, line: usize) -> usize {
+    size.div_ceil(line)
+}
+
+/// Returns the padding needed after a field of `first_size` bytes, so that
+/// the next field starts on a fresh cache line of `line` bytes.
+///
+/// This assumes the first field starts on a cache line boundary, and is
+/// intended for keeping concurrently accessed fields from false sharing.
+/// The padding may be split into padding types with
+/// [`decompose`](crate::decompose).
+///
+/// # Panics
+///
+/// Panics if `line` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::separation_pad;
+///
+/// assert_eq!(separation_pad(8, 64), 56);
+/// assert_eq!(separation_pad(64, 64), 0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn separation_pad(first_size: usize, line: usize) -> usize {
+    padding_for(first_size, line)
+}
+
+/// Returns the trailing padding needed to round `size` up to the next
+/// power of two.
+///
+/// This is intended for allocator size classes. Sizes which are already
+/// powers of two need no padding, and neither does an empty struct. The
+/// padding may be split into padding types with [`decompose`](crate::decompose).
+///
+/// # Panics
+///
+/// Panics if the next power of two does not fit in a `usize`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::next_pow2_pad;
+///
+/// assert_eq!(next_pow2_pad(9), 7);
+/// assert_eq!(next_pow2_pad(16), 0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn next_pow2_pad(size: usize) -> usize {
+    if size == 0 {
+        return 0;
+    }
+    match size.checked_next_power_of_two() {
+        Some(pow2) => pow2 - size,
+        None => panic!("the next power of two overflows a usize"),
+    }
+}
+
+macro_rules! platform_align {
+    ($($name:ident => $ty:ty),*) => {
+        $(
+            #[doc = concat!("Returns the alignment of `", stringify!($ty), "` on the target platform.")]
+            ///
+            /// This is a shorthand for use in portable layout calculations.
+            #[inline]
+            #[must_use]
+            pub const fn $name() -> usize {
+                core::mem::align_of::<$ty>()
+            }
+        )*
+    };
+}
+
+platform_align!(
+    platform_align_of_u8 => u8,
+    platform_align_of_u16 => u16,
+    platform_align_of_u32 => u32,
+    platform_align_of_u64 => u64,
+    platform_align_of_u128 => u128,
+    platform_align_of_usize => usize,
+    platform_align_of_f32 => f32,
+    platform_align_of_f64 => f64,
+    platform_align_of_ptr => *const ()
+);
+
+/// A `#[repr(C)]` struct layout, built one field at a time.
+///
+/// Every method is a `const fn`, so a layout may be computed at compile
+/// time and compared against the size and alignment of a real struct.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::{align_of, size_of};
+/// use struct_pad::{PadU32, StructLayout};
+///
+/// #[repr(C)]
+/// struct Example {
+///     a: u32,
+///     pad: PadU32,
+///     b: u64,
+/// }
+///
+/// const LAYOUT: StructLayout = StructLayout::new()
+///     .field(4, 4)
+///     .pad(4)
+///     .field(size_of::<u64>(), align_of::<u64>());
+/// assert_eq!(LAYOUT.size(), size_of::<Example>());
+/// assert_eq!(LAYOUT.align(), align_of::<Example>());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StructLayout {
+    offset: usize,
+    align: usize,
+}
+
+impl StructLayout {
+    /// Returns the layout of a struct with no fields.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            align: 1,
+        }
+    }
+
+    /// Appends a field of the given size and alignment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is zero, or, in debug builds, if the offset
+    /// overflows a `usize`. See [`checked_field`](Self::checked_field)
+    /// for a variant which returns `None` instead.
+    #[inline]
+    #[must_use]
+    pub const fn field(self, size: usize, align: usize) -> Self {
+        let offset = self.offset + padding_for(self.offset, align) + size;
+        let align = if align > self.align {
+            align
+        } else {
+            self.align
+        };
+        Self { offset, align }
+    }
+
+    /// Appends `size` bytes of padding with an alignment of 1,
+    /// such as a [`PadBytes`](crate::PadBytes) field.
+    #[inline]
+    #[must_use]
+    pub const fn pad(self, size: usize) -> Self {
+        self.field(size, 1)
+    }
+
+    /// Like [`field`](Self::field), but returns `None` if the offset
+    /// overflows a `usize`, or if `align` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use struct_pad::StructLayout;
+    ///
+    /// let layout = StructLayout::new().checked_field(usize::MAX, 1);
+    /// assert!(layout.is_some());
+    /// assert!(layout.unwrap().checked_field(1, 1).is_none());
+    /// assert!(StructLayout::new().checked_field(1, 0).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_field(self, size: usize, align: usize) -> Option<Self> {
+        let gap = try_opt!(checked_padding_for(self.offset, align));
+        let offset = try_opt!(self.offset.checked_add(gap));
+        let offset = try_opt!(offset.checked_add(size));
+        let align = if align > self.align {
+            align
+        } else {
+            self.align
+        };
+        Some(Self { offset, align })
+    }
+
+    /// Like [`pad`](Self::pad), but returns `None` if the offset overflows
+    /// a `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn checked_pad(self, size: usize) -> Option<Self> {
+        self.checked_field(size, 1)
+    }
+
+    /// Returns the offset just past the last field,
+    /// before any trailing padding.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the size of the struct, including any trailing padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the size overflows a `usize`. See
+    /// [`checked_size`](Self::checked_size) for a variant which returns
+    /// `None` instead.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.offset + padding_for(self.offset, self.align)
+    }
+
+    /// Like [`size`](Self::size), but returns `None` if the size overflows
+    /// a `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn checked_size(&self) -> Option<usize> {
+        self.offset
+            .checked_add(padding_for(self.offset, self.align))
+    }
+
+    /// Returns the alignment of the struct.
+    #[inline]
+    #[must_use]
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+}
+
+impl Default for StructLayout {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding() {
+        assert_eq!(total_padding(&[]), 0);
+        assert_eq!(total_padding(&[(1, 1), (8, 8)]), 7);
+        assert_eq!(total_padding(&[(8, 8), (1, 1)]), 7);
+        assert_eq!(total_padding(&[(1, 1), (2, 2), (4, 4)]), 1);
+        assert_eq!(total_padding(&[(4, 4), (2, 2), (1, 1), (1, 1)]), 0);
+        assert_eq!(total_padding(&[(1, 1), (4, 4), (1, 1)]), 6);
+    }
+
+    #[test]
+    fn tail() {
+        assert_eq!(tail_padding(&[]), 0);
+        assert_eq!(tail_padding(&[(1, 1), (4, 4)]), 0);
+        assert_eq!(total_padding(&[(1, 1), (4, 4)]), 3);
+        assert_eq!(tail_padding(&[(8, 8), (1, 1)]), 7);
+        assert_eq!(tail_padding(&[(1, 1), (4, 4), (1, 1)]), 3);
+        assert_eq!(tail_padding(&[(4, 4), (2, 2), (1, 1), (1, 1)]), 0);
+    }
+
+    #[test]
+    fn offsets() {
+        use core::mem::{align_of, offset_of, size_of};
+
+        #[repr(C)]
+        struct Example {
+            a: u8,
+            b: u32,
+            c: u16,
+            d: u64,
+        }
+
+        let fields = [
+            (size_of::<u8>(), align_of::<u8>()),
+            (size_of::<u32>(), align_of::<u32>()),
+            (size_of::<u16>(), align_of::<u16>()),
+            (size_of::<u64>(), align_of::<u64>()),
+        ];
+        assert_eq!(field_offset(&[(1, 1), (8, 8)], 0), 0);
+        assert_eq!(field_offset(&[(1, 1), (8, 8)], 1), 8);
+        assert_eq!(field_offset(&[(1, 1), (0, 16)], 1), 16);
+        assert_eq!(field_offset(&fields, 1), offset_of!(Example, b));
+        assert_eq!(field_offset(&fields, 2), offset_of!(Example, c));
+        assert_eq!(field_offset(&fields, 3), offset_of!(Example, d));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn offset_out_of_range() {
+        let _ = field_offset(&[(1, 1)], 1);
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(struct_size(&[]), 0);
+        assert_eq!(struct_size(&[(1, 1), (4, 4)]), 8);
+        assert_eq!(struct_size(&[(8, 8), (1, 1)]), 16);
+        assert_eq!(struct_size(&[(1, 1), (1, 1), (2, 2)]), 4);
+        assert_eq!(struct_size(&[(0, 16)]), 0);
+        assert_eq!(struct_size(&[(1, 1), (0, 16)]), 16);
+    }
+
+    #[test]
+    fn ordering() {
+        let loose = [(1, 1), (8, 8), (1, 1)];
+        let tight = [(8, 8), (1, 1), (1, 1)];
+        assert_eq!(size_of_ordering(&loose), 24);
+        assert_eq!(size_of_ordering(&tight), 16);
+        assert!(size_of_ordering(&tight) < size_of_ordering(&loose));
+        assert_eq!(
+            size_of_ordering(&[(2, 2), (4, 4), (2, 2)]),
+            size_of_ordering(&[(4, 4), (2, 2), (2, 2)]) + 4
+        );
+    }
+
+    #[test]
+    fn savings() {
+        assert_eq!(layout_savings(24, 16), 8);
+        assert_eq!(layout_savings(16, 24), -8);
+        assert_eq!(layout_savings(16, 16), 0);
+        assert_eq!(layout_savings(0, 0), 0);
+        assert_eq!(layout_savings(isize::MAX as usize, 0), isize::MAX);
+        assert_eq!(layout_savings(0, isize::MAX as usize), -isize::MAX);
+        assert_eq!(layout_savings(isize::MAX as usize, usize::MAX), isize::MIN);
+        assert_eq!(
+            layout_savings(
+                size_of_ordering(&[(1, 1), (4, 4), (1, 1)]),
+                size_of_ordering(&[(4, 4), (1, 1), (1, 1)])
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn reserve() {
+        use crate::{Pad, PadBytes};
+        use core::mem::size_of;
+
+        #[allow(dead_code)]
+        #[repr(C)]
+        union Variants {
+            a: u32,
+            b: [u8; 12],
+            c: [u16; 4],
+        }
+
+        assert_eq!(union_reserve(&[]), 0);
+        assert_eq!(union_reserve(&[4, 12, 8]), 12);
+        assert_eq!(union_reserve(&[7]), 7);
+        assert_eq!(
+            PadBytes::<{ union_reserve(&[4, 12, 8]) }>::SIZE,
+            size_of::<Variants>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn optimal() {
+        assert!(optimal_ordering(&[]).is_empty());
+        assert_eq!(optimal_ordering(&[(4, 4, "a")]), ["a"]);
+        assert_eq!(
+            optimal_ordering(&[(1, 1, "a"), (8, 8, "b"), (1, 1, "c")]),
+            ["a", "c", "b"]
+        );
+        assert_eq!(
+            optimal_ordering(&[(8, 8, "a"), (1, 1, "b"), (1, 1, "c")]),
+            ["a", "b", "c"]
+        );
+        let fields = [
+            (1, 1, "a"),
+            (4, 4, "b"),
+            (2, 2, "c"),
+            (8, 8, "d"),
+            (1, 1, "e"),
+            (4, 4, "f"),
+            (2, 2, "g"),
+            (8, 8, "h"),
+        ];
+        let order = optimal_ordering(&fields);
+        let layout: Vec<_> = order
+            .iter()
+            .map(|name| fields.iter().find(|field| field.2 == *name).unwrap())
+            .map(|&(size, align, _)| (size, align))
+            .collect();
+        assert_eq!(order.len(), fields.len());
+        assert_eq!(struct_size(&layout), 32);
+        assert_eq!(total_padding(&layout), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn permutations() {
+        let mut order = [0, 1, 2, 3];
+        let mut count = 1;
+        while next_permutation(&mut order) {
+            count += 1;
+        }
+        assert_eq!(count, 24);
+        assert_eq!(order, [3, 2, 1, 0]);
+        assert!(!next_permutation(&mut []));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "more than 8 fields")]
+    fn optimal_too_many() {
+        let _ = optimal_ordering(&[(1, 1, "x"); 9]);
+    }
+
+    #[test]
+    fn tightly_packed() {
+        assert!(is_tightly_packed(&[], 0));
+        assert!(is_tightly_packed(&[(4, 4), (2, 2), (1, 1), (1, 1)], 8));
+        assert!(is_tightly_packed(&[(8, 8), (8, 8)], 16));
+        assert!(!is_tightly_packed(&[(4, 4), (2, 2), (1, 1), (1, 1)], 12));
+        assert!(!is_tightly_packed(&[(4, 4), (1, 1)], 8));
+        assert!(!is_tightly_packed(&[(4, 4), (1, 1)], 5));
+        assert!(!is_tightly_packed(&[(1, 1), (4, 4)], 8));
+        assert!(!is_tightly_packed(&[(1, 1), (4, 4)], 5));
+    }
+
+    #[test]
+    fn contiguous() {
+        assert!(is_contiguous(&[]));
+        assert!(is_contiguous(&[(1, 1), (3, 1), (2, 1)]));
+        assert!(is_contiguous(&[(4, 4), (2, 2), (1, 1)]));
+        assert!(is_contiguous(&[(8, 8), (1, 1)]));
+        assert!(!is_contiguous(&[(1, 1), (4, 4)]));
+        assert!(!is_contiguous(&[(2, 2), (1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn checked() {
+        let fields = [(1, 1), (2, 2), (4, 4), (8, 8), (1, 1)];
+        assert_eq!(checked_struct_size(&fields), Some(struct_size(&fields)));
+        assert_eq!(checked_total_padding(&fields), Some(total_padding(&fields)));
+        assert_eq!(checked_struct_size(&[]), Some(0));
+        assert_eq!(checked_struct_size(&[(usize::MAX, 1)]), Some(usize::MAX));
+        assert_eq!(checked_struct_size(&[(usize::MAX, 1), (1, 1)]), None);
+        assert_eq!(checked_struct_size(&[(usize::MAX - 1, 1), (1, 2)]), None);
+        assert_eq!(checked_struct_size(&[(usize::MAX - 2, 4)]), None);
+        assert_eq!(checked_total_padding(&[(usize::MAX - 2, 4)]), None);
+        assert_eq!(checked_total_padding(&[(usize::MAX, 1), (0, 2)]), None);
+        assert_eq!(checked_struct_size(&[(1, 0)]), None);
+        assert_eq!(checked_struct_size(&[(1, 1), (4, 3)]), None);
+        assert_eq!(checked_total_padding(&[(1, 0)]), None);
+        assert_eq!(checked_total_padding(&[(6, 6)]), None);
+    }
+
+    #[test]
+    fn checked_helpers() {
+        let fields = [(1, 1), (2, 2), (4, 4), (8, 8), (1, 1)];
+        assert_eq!(checked_tail_padding(&fields), Some(tail_padding(&fields)));
+        assert_eq!(checked_tail_padding(&[]), Some(0));
+        assert_eq!(checked_tail_padding(&[(usize::MAX - 2, 4)]), None);
+        assert_eq!(checked_tail_padding(&[(usize::MAX, 1), (1, 1)]), None);
+        for n in 0..fields.len() {
+            assert_eq!(
+                checked_field_offset(&fields, n),
+                Some(field_offset(&fields, n))
+            );
+        }
+        assert_eq!(checked_field_offset(&fields, fields.len()), None);
+        assert_eq!(checked_field_offset(&[], 0), None);
+        assert_eq!(checked_field_offset(&[(usize::MAX, 1), (1, 2)], 1), None);
+        assert_eq!(checked_field_offset(&[(1, 0), (1, 1)], 1), None);
+        assert_eq!(checked_field_offset(&[(1, 1), (4, 3)], 1), None);
+        assert_eq!(checked_tail_padding(&[(1, 0)]), None);
+        assert_eq!(checked_tail_padding(&[(8, 8), (1, 12)]), None);
+        assert_eq!(
+            checked_field_offset(&[(usize::MAX, 1), (0, 1)], 1),
+            Some(usize::MAX)
+        );
+        assert_eq!(checked_layout_savings(16, 24), Some(-8));
+        assert_eq!(
+            checked_layout_savings(0, isize::MAX as usize),
+            Some(-isize::MAX)
+        );
+        assert_eq!(checked_layout_savings(0, isize::MAX as usize + 1), None);
+        assert_eq!(checked_layout_savings(usize::MAX, 0), None);
+        assert_eq!(union_reserve(&[usize::MAX, 1]), usize::MAX);
+    }
+
+    #[test]
+    fn fits_target() {
+        assert!(fits(12, 4, 16));
+        assert!(fits(8, 4, 16));
+        assert!(fits(16, 0, 16));
+        assert!(!fits(12, 8, 16));
+        assert!(!fits(17, 0, 16));
+        assert!(!fits(usize::MAX, 1, usize::MAX));
+    }
+
+    #[test]
+    fn overfills() {
+        assert!(pad_overfills(2, 4));
+        assert!(pad_overfills(0, 1));
+        assert!(!pad_overfills(4, 4));
+        assert!(!pad_overfills(4, 2));
+        assert!(!pad_overfills(0, 0));
+    }
+
+    #[test]
+    fn well_formed() {
+        assert!(is_well_formed(0, 1));
+        assert!(is_well_formed(8, 4));
+        assert!(is_well_formed(16, 16));
+        assert!(!is_well_formed(6, 4));
+        assert!(!is_well_formed(1, 2));
+        assert!(!is_well_formed(0, 0));
+    }
+
+    #[test]
+    fn matches_repr_c() {
+        use core::mem::{align_of, size_of};
+
+        #[repr(C)]
+        struct Example {
+            a: u8,
+            b: u32,
+            c: u16,
+            d: u64,
+        }
+
+        let fields = [
+            (size_of::<u8>(), align_of::<u8>()),
+            (size_of::<u32>(), align_of::<u32>()),
+            (size_of::<u16>(), align_of::<u16>()),
+            (size_of::<u64>(), align_of::<u64>()),
+        ];
+        let sum: usize = fields.iter().map(|&(size, _)| size).sum();
+        assert_eq!(total_padding(&fields), size_of::<Example>() - sum);
+        assert_eq!(struct_size(&fields), size_of::<Example>());
+    }
+
+    #[test]
+    fn raises() {
+        assert!(raises_align(4, 8));
+        assert!(raises_align(1, 2));
+        assert!(!raises_align(8, 4));
+        assert!(!raises_align(4, 4));
+        let layout = StructLayout::new().field(4, 4);
+        assert_eq!(
+            raises_align(layout.align(), 8),
+            layout.field(8, 8).align() > layout.align()
+        );
+    }
+
+    #[test]
+    fn nested() {
+        use crate::PadU16;
+        use core::mem::{align_of, size_of};
+
+        #[repr(C)]
+        struct Child {
+            a: u8,
+            b: u32,
+        }
+
+        #[repr(C)]
+        struct Parent {
+            a: u32,
+            b: u16,
+            pad: PadU16,
+            child: Child,
+        }
+
+        assert_eq!(align_nested(6, 8), 2);
+        assert_eq!(align_nested(0, 8), 0);
+        assert_eq!(align_nested(9, 4), 3);
+        assert_eq!(align_nested(6, align_of::<Child>()), size_of::<PadU16>());
+        assert_eq!(core::mem::offset_of!(Parent, child), 8);
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(cache_lines(0, 64), 0);
+        assert_eq!(cache_lines(1, 64), 1);
+        assert_eq!(cache_lines(64, 64), 1);
+        assert_eq!(cache_lines(65, 64), 2);
+        assert_eq!(cache_lines(256, 128), 2);
+        assert_eq!(cache_lines(usize::MAX, 64), usize::MAX / 64 + 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lines_zero() {
+        let _ = cache_lines(1, 0);
+    }
+
+    #[test]
+    fn separation() {
+        assert_eq!(separation_pad(8, 64), 56);
+        assert_eq!(separation_pad(0, 64), 0);
+        assert_eq!(separation_pad(64, 64), 0);
+        assert_eq!(separation_pad(65, 64), 63);
+        assert_eq!(separation_pad(24, 128), 104);
+        for size in 0..200 {
+            assert_eq!((size + separation_pad(size, 64)) % 64, 0);
+            assert_eq!(
+                cache_lines(size + separation_pad(size, 64), 64),
+                cache_lines(size, 64)
+            );
+        }
+    }
+
+    #[test]
+    fn pow2_pad() {
+        assert_eq!(next_pow2_pad(0), 0);
+        assert_eq!(next_pow2_pad(1), 0);
+        assert_eq!(next_pow2_pad(3), 1);
+        assert_eq!(next_pow2_pad(9), 7);
+        assert_eq!(next_pow2_pad(16), 0);
+        assert_eq!(next_pow2_pad(17), 15);
+        assert_eq!(next_pow2_pad(1000), 24);
+        assert_eq!(next_pow2_pad(usize::MAX / 2 + 1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn pow2_pad_overflow() {
+        let _ = next_pow2_pad(usize::MAX / 2 + 2);
+    }
+
+    #[test]
+    fn platform_align() {
+        use core::mem::align_of;
+
+        assert_eq!(platform_align_of_u8(), align_of::<u8>());
+        assert_eq!(platform_align_of_u16(), align_of::<u16>());
+        assert_eq!(platform_align_of_u32(), align_of::<u32>());
+        assert_eq!(platform_align_of_u64(), align_of::<u64>());
+        assert_eq!(platform_align_of_u128(), align_of::<u128>());
+        assert_eq!(platform_align_of_usize(), align_of::<usize>());
+        assert_eq!(platform_align_of_f32(), align_of::<f32>());
+        assert_eq!(platform_align_of_f64(), align_of::<f64>());
+        assert_eq!(platform_align_of_ptr(), align_of::<*const u8>());
+        const SIZE: usize = struct_size(&[(1, 1), (8, platform_align_of_u64())]);
+        assert_eq!(SIZE, 8 + platform_align_of_u64());
+    }
+
+    #[test]
+    fn builder() {
+        use crate::{PadU16, PadU8};
+        use core::mem::{align_of, size_of};
+
+        #[repr(C)]
+        struct Example {
+            a: u8,
+            pad1: PadU8,
+            pad2: PadU16,
+            b: u32,
+            c: u16,
+            d: u64,
+        }
+
+        const LAYOUT: StructLayout = StructLayout::new()
+            .field(1, 1)
+            .pad(1)
+            .field(2, 2)
+            .field(4, 4)
+            .field(2, 2)
+            .field(size_of::<u64>(), align_of::<u64>());
+        assert_eq!(LAYOUT.offset(), size_of::<Example>());
+        assert_eq!(LAYOUT.size(), size_of::<Example>());
+        assert_eq!(LAYOUT.align(), align_of::<Example>());
+
+        let trailing = StructLayout::new().field(8, 8).field(1, 1);
+        assert_eq!(trailing.offset(), 9);
+        assert_eq!(trailing.size(), struct_size(&[(8, 8), (1, 1)]));
+        assert_eq!(StructLayout::default(), StructLayout::new());
+        assert_eq!(StructLayout::new().size(), 0);
+        assert_eq!(StructLayout::new().align(), 1);
+    }
+
+    #[test]
+    fn builder_checked() {
+        let layout = StructLayout::new().field(1, 1).field(4, 4);
+        assert_eq!(
+            StructLayout::new()
+                .checked_field(1, 1)
+                .and_then(|l| l.checked_field(4, 4)),
+            Some(layout)
+        );
+        assert_eq!(
+            StructLayout::new().checked_pad(3).map(|l| l.offset()),
+            Some(3)
+        );
+        assert_eq!(layout.checked_size(), Some(layout.size()));
+
+        let full = StructLayout::new().field(usize::MAX, 1);
+        assert_eq!(full.checked_size(), Some(usize::MAX));
+        assert_eq!(full.checked_field(1, 1), None);
+        assert_eq!(full.checked_pad(1), None);
+        assert_eq!(full.checked_field(0, 2), None);
+        assert_eq!(StructLayout::new().checked_field(1, 0), None);
+        assert_eq!(StructLayout::new().checked_field(1, 3), None);
+
+        let tail = StructLayout::new().field(4, 4).field(usize::MAX - 4, 1);
+        assert_eq!(tail.offset(), usize::MAX);
+        assert_eq!(tail.checked_size(), None);
+    }
+}
@@ -1,6 +1,6 @@
 #![no_std]
-#![allow(clippy::incorrect_clone_impl_on_copy_type)]
-#![allow(clippy::incorrect_partial_ord_impl_on_ord_type)]
+#![allow(clippy::non_canonical_clone_impl)]
+#![allow(clippy::non_canonical_partial_ord_impl)]
 //! Padding types to enable memory layout optimizations.
 //!
 //! # Example
@@ -37,9 +37,179 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Replacing implicit padding
+//!
+//! A `#[repr(C)]` struct with compiler-inserted padding may be rewritten
+//! with explicit padding fields, keeping its size and alignment. The
+//! structs below are over-aligned to 8 bytes, so that the layout is the same
+//! on targets where `u64` is only 4-byte aligned.
+//!
+//! ```rust
+//! use core::mem::{align_of, size_of};
+//! use struct_pad::{Pad, PadU16, PadU32, PadU8};
+//!
+//! // 7 bytes of implicit padding follow `b`.
+//! #[repr(C, align(8))]
+//! struct Before {
+//!     a: u64,
+//!     b: u8,
+//! }
+//!
+//! #[repr(C, align(8))]
+//! struct After {
+//!     a: u64,
+//!     b: u8,
+//!     pad1: PadU8,
+//!     pad2: PadU16,
+//!     pad4: PadU32,
+//! }
+//!
+//! assert_eq!(size_of::<Before>(), 16);
+//! assert_eq!(size_of::<After>(), size_of::<Before>());
+//! assert_eq!(align_of::<After>(), align_of::<Before>());
+//!
+//! let after = After {
+//!     a: 1,
+//!     b: 2,
+//!     pad1: Pad::VALUE,
+//!     pad2: Pad::VALUE,
+//!     pad4: Pad::VALUE,
+//! };
+//! assert_eq!((after.a, after.b), (1, 2));
+//! ```
+//!
+//! # Enum layout
+//!
+//! A `#[repr(C, u8)]` enum is laid out as a `u8` discriminant followed by a
+//! union of its payloads, so padding is inserted between the two whenever
+//! a payload is more aligned than the discriminant. A struct mimicking such
+//! an enum may spell out that gap with a padding field.
+//!
+//! ```rust
+//! use core::mem::{align_of, size_of};
+//! use struct_pad::{Pad, PadU8};
+//!
+//! // 1 byte of implicit padding follows the discriminant.
+//! #[repr(C, u8)]
+//! enum Message {
+//!     Ping = 1,
+//!     Data(u16) = 2,
+//! }
+//!
+//! #[repr(C)]
+//! struct RawMessage {
+//!     tag: u8,
+//!     pad: PadU8,
+//!     payload: u16,
+//! }
+//!
+//! assert_eq!(size_of::<Message>(), 4);
+//! assert_eq!(size_of::<RawMessage>(), size_of::<Message>());
+//! assert_eq!(align_of::<RawMessage>(), align_of::<Message>());
+//!
+//! let message = Message::Data(7);
+//! let raw = RawMessage {
+//!     tag: 2,
+//!     pad: Pad::VALUE,
+//!     payload: 7,
+//! };
+//! assert!(matches!(message, Message::Data(7)));
+//! assert!(!matches!(Message::Ping, Message::Data(_)));
+//! assert_eq!((raw.tag, raw.payload), (2, 7));
+//! ```
+//!
+//! # Features
+//!
+//! - `alloc`: enables helpers which allocate, such as [`optimal_ordering`].
+//! - `bytemuck`: implements `Zeroable` and `NoUninit` for the padding types,
+//!   so that structs containing pads may be viewed as bytes. Pads are not
+//!   `Pod`, since they have only one valid bit-pattern.
+//! - `musli`: implements `Encode` and `Decode` for the padding types. Pads
+//!   are encoded as a pack of zero bytes. This feature requires Rust 1.85,
+//!   rather than the crate's minimum supported Rust version of 1.77.
+//! - `num-traits`: implements `Zero` and `Add` for the padding types, so
+//!   that pads may flow through generic zero-initialization code.
+//! - `serde`: implements `Serialize` and `Deserialize` for the padding types.
+//!   Pads are serialized as a tuple of zero bytes, so that formats such as
+//!   `postcard` reproduce their in-memory size.
+//!
+//! # Soundness
+//!
+//! Every padding type has exactly one valid bit-pattern, which is all zeros.
+//! It is therefore sound to create pads with `core::mem::zeroed`, and to
+//! bit-copy or transmute structs containing pads, provided the pad bytes
+//! are zero. The test suite exercises these patterns.
+//!
+//! # Thread safety
+//!
+//! All padding types are `Send` and `Sync`, so they may be freely embedded
+//! in data structures which are shared between threads. The one exception
+//! is [`PadMarker<T>`], which is `Send` and `Sync` only if `T` is.
+//!
+//! Padding types are also inert with respect to concurrency: they have no
+//! interior mutability, and every write to a pad, such as [`Pad::reset`] or
+//! [`write_pad`], stores the only valid bit-pattern. Sharing a pad is
+//! therefore sound, and it cannot race with neighbouring atomics. A pad
+//! placed next to an atomic field in a `#[repr(C)]` struct only shifts the
+//! atomic's offset as any other field of the same layout would.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use core::cmp::Ordering;
+use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use core::ops::{Index, Range};
+
+mod align;
+mod any;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod contract;
+mod convert;
+mod copy;
+mod layout;
+#[cfg(feature = "musli")]
+mod musli;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod padded;
+mod primitive;
+mod region;
+mod scratch;
+#[cfg(feature = "serde")]
+mod serde;
+mod size;
+mod tuple;
+
+pub use align::{Align, AlignTo, Alignment};
+pub use any::{
+    decompose, decompose_into, decompose_len, nearest_available_size, pad_by_name, AnyPad,
+    Decompose, PadMismatch, AVAILABLE_PAD_SIZES_MASK, BUILTIN_PADS, BUILTIN_PAD_COUNT,
+};
+pub use contract::{DontCarePad, ZeroedPad};
+pub use convert::NonZeroPadError;
+pub use copy::BitCopyablePad;
+pub use layout::{
+    align_nested, cache_lines, checked_field_offset, checked_layout_savings, checked_struct_size,
+    checked_tail_padding, checked_total_padding, field_offset, fits, is_contiguous,
+    is_tightly_packed, is_well_formed, layout_savings, next_pow2_pad, pad_overfills,
+    platform_align_of_f32, platform_align_of_f64, platform_align_of_ptr, platform_align_of_u128,
+    platform_align_of_u16, platform_align_of_u32, platform_align_of_u64, platform_align_of_u8,
+    platform_align_of_usize, raises_align, separation_pad, size_of_ordering, struct_size,
+    tail_padding, total_padding, union_reserve, StructLayout,
+};
+#[cfg(feature = "alloc")]
+pub use layout::{optimal_ordering, MAX_ORDERING_FIELDS};
+pub use padded::Padded;
+pub use primitive::{HasPad, PadMatching, PadToPrimitive};
+pub use region::PadRegion;
+pub use scratch::ScratchWord;
+pub use size::SizeIs;
+pub use tuple::PadTupleSize;
 
 /// A padding type.
 /// 
@@ -47,31 +217,245 @@ use core::hash::{Hash, Hasher};
 ///
 /// This trait is provided so that downstream crates may 
 /// construct pad values generically within `const fn`'s.
+///
+/// Padding types deliberately do not implement [`fmt::Pointer`]: they hold
+/// no address, so formatting one with `{:p}` is rejected at compile time.
+/// To print the address of a padding field, format a reference to it.
+///
+/// ```compile_fail
+/// use struct_pad::{Pad, PadU64};
+///
+/// println!("{:p}", PadU64::VALUE);
+/// ```
+///
+/// [`fmt::Pointer`]: core::fmt::Pointer
 pub trait Pad: Copy + private::Sealed {
     /// The only valid `Pad` value.
     const VALUE: Self;
+
+    /// The size of `Self` in bytes.
+    ///
+    /// For a concrete padding type, `SIZE` may be used wherever a constant
+    /// is expected, such as an array length or a const generic argument:
+    ///
+    /// ```rust
+    /// use struct_pad::{Pad, PadBytes, PadU32};
+    ///
+    /// let bytes = [0u8; PadU32::SIZE];
+    /// let pad: PadBytes<{ PadU32::SIZE }> = Pad::VALUE;
+    /// ```
+    ///
+    /// Using `SIZE` of a generic padding type in the same way requires the
+    /// unstable `generic_const_exprs` feature. On stable Rust, generic code
+    /// may use [`Pad::bytes`] instead.
+    ///
+    /// ```compile_fail
+    /// use struct_pad::Pad;
+    ///
+    /// fn zeros<P: Pad>() -> [u8; P::SIZE] {
+    ///     [0; P::SIZE]
+    /// }
+    /// ```
+    const SIZE: usize = size_of::<Self>();
+
+    /// The alignment of `Self` in bytes.
+    const ALIGN: usize = align_of::<Self>();
+
+    /// Returns [`VALUE`](Pad::VALUE), ignoring `seed`.
+    ///
+    /// This lets seeded test harnesses construct every field of a struct
+    /// uniformly: real fields derive their value from the seed, while
+    /// padding fields ignore it.
+    ///
+    /// ```rust
+    /// use struct_pad::{Pad, PadU32};
+    ///
+    /// assert_eq!(PadU32::from_seed(42), PadU32::VALUE);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn from_seed(_seed: u64) -> Self {
+        Self::VALUE
+    }
+
+    /// Sets `self` to [`VALUE`](Pad::VALUE).
+    ///
+    /// This states the intent to clear a padding field back to zero,
+    /// for instance when reusing a buffer between FFI calls.
+    #[inline]
+    fn reset(&mut self) {
+        *self = Self::VALUE;
+    }
+
+    /// Returns the bytes of `self`, all of which are zero.
+    ///
+    /// The returned slice has a length of [`SIZE`](Pad::SIZE), so that
+    /// generic code may iterate over the bytes of pads and of other fields
+    /// uniformly.
+    #[inline]
+    fn bytes(&self) -> &'static [u8] {
+        &ZEROS[..Self::SIZE]
+    }
+
+    /// Checks that `bytes`, received in place of `self`, are all zero.
+    ///
+    /// This is intended for defensive FFI: after receiving a struct from
+    /// foreign code, the bytes of its padding fields may be checked to catch
+    /// protocol bugs early. Like [`debug_assert!`], the check is only
+    /// performed in debug builds, and is a no-op otherwise.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `bytes` is not [`SIZE`](Pad::SIZE) bytes
+    /// long, or if any of its bytes is nonzero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use struct_pad::{Pad, PadU32};
+    ///
+    /// PadU32::VALUE.assert_clean(&[0; 4]);
+    /// ```
+    #[inline]
+    fn assert_clean(&self, bytes: &[u8]) {
+        debug_assert_eq!(bytes.len(), Self::SIZE, "padding has the wrong size");
+        debug_assert!(all_zero(bytes), "padding must be zero");
+    }
+
+    /// Whether `Self` has the same size as `T`.
+    ///
+    /// This lets tooling check that a pad was chosen to stand in for a
+    /// field of type `T`.
+    ///
+    /// ```rust
+    /// use struct_pad::{Pad, PadU32};
+    ///
+    /// assert!(PadU32::matches_primitive_size::<f32>());
+    /// assert!(!PadU32::matches_primitive_size::<u64>());
+    /// ```
+    #[inline]
+    #[must_use]
+    fn matches_primitive_size<T>() -> bool {
+        Self::SIZE == size_of::<T>()
+    }
+
+    /// Returns the range of byte offsets covered by `self`,
+    /// when placed at offset `base`.
+    ///
+    /// This is intended for memory-map diagrams which highlight padding.
+    ///
+    /// ```rust
+    /// use struct_pad::{Pad, PadU32};
+    ///
+    /// assert_eq!(PadU32::VALUE.byte_offsets(8), 8..12);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn byte_offsets(&self, base: usize) -> Range<usize> {
+        base..base + Self::SIZE
+    }
+
+    /// Returns the contribution of `self` to the alignment of a struct
+    /// containing it, which is [`ALIGN`](Pad::ALIGN).
+    ///
+    /// The alignment of a struct is the largest contribution of its fields.
+    ///
+    /// ```rust
+    /// use struct_pad::{Pad, PadU16, PadU32, PadU8};
+    ///
+    /// let contributions = [
+    ///     PadU8::VALUE.align_contribution(),
+    ///     PadU32::VALUE.align_contribution(),
+    ///     PadU16::VALUE.align_contribution(),
+    /// ];
+    /// assert_eq!(contributions.iter().fold(1, |a, &b| a.max(b)), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn align_contribution(&self) -> usize {
+        Self::ALIGN
+    }
+
+    /// Whether `Option<Self>` has the same size as `Self`.
+    ///
+    /// Padding types which occupy memory have only one valid bit-pattern,
+    /// leaving every other bit-pattern free for the compiler to use as a
+    /// niche. Zero-sized padding types have no bits to spare, so wrapping
+    /// them in an `Option` adds a byte for the discriminant.
+    const HAS_NICHE: bool;
 }
 
 /// A padding type with the same layout as `()`.
 ///
 /// Like the other padding types, `PadU0` implements the `Pad` trait.
 /// However, it occupies no space in memory.
+///
+/// `PadU0` is implemented as an empty array, so that it is
+/// FFI-safe when used as a field of a `#[repr(C)]` struct.
 #[derive(Debug)]
-pub struct PadU0(());
+#[repr(C)]
+pub struct PadU0([u8; 0]);
 
-impl Clone for PadU0 {
+impl PadU0 {
+    /// Returns the name of this padding type.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadU0"
+    }
+
+    /// Returns a fresh `PadU0`, for use as a zero-sized capability token.
+    ///
+    /// Unlike `()`, a token of a named type documents its purpose in
+    /// signatures, and may be stored alongside other padding fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use struct_pad::PadU0;
+    ///
+    /// fn requires_token(_: PadU0) {}
+    ///
+    /// requires_token(PadU0::token());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn token() -> Self {
+        Self::VALUE
+    }
+
+    /// Splits a token into two, modelling a capability being shared with
+    /// two holders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use struct_pad::PadU0;
+    ///
+    /// let (left, right) = PadU0::token().split_token();
+    /// assert_eq!(left, right);
+    /// ```
     #[inline]
     #[must_use]
+    pub const fn split_token(self) -> (Self, Self) {
+        (Self::VALUE, Self::VALUE)
+    }
+}
+
+impl Clone for PadU0 {
+    #[inline]
     fn clone(&self) -> Self {
         Self::VALUE
     }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
 }
 
 impl Copy for PadU0 {}
 
 impl Default for PadU0 {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::VALUE
     }
@@ -84,21 +468,29 @@ impl Hash for PadU0 {
     fn hash<H: Hasher>(&self, _: &mut H) {}
 }
 
+impl Index<usize> for PadU0 {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
 impl Ord for PadU0 {
     #[inline]
-    #[must_use]
     fn cmp(&self, _: &Self) -> Ordering {
         Ordering::Equal
     }
 }
 
 impl Pad for PadU0 {
-    const VALUE: Self = Self(());
+    const VALUE: Self = Self([]);
+    const HAS_NICHE: bool = false;
 }
 
 impl PartialEq for PadU0 {
     #[inline]
-    #[must_use]
     fn eq(&self, _: &Self) -> bool {
         true
     }
@@ -106,7 +498,6 @@ impl PartialEq for PadU0 {
 
 impl PartialOrd for PadU0 {
     #[inline]
-    #[must_use]
     fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
         Some(Ordering::Equal)
     }
@@ -120,19 +511,29 @@ impl PartialOrd for PadU0 {
 #[repr(transparent)]
 pub struct PadU8(PadU8Inner);
 
-impl Clone for PadU8 {
+impl PadU8 {
+    /// Returns the name of this padding type.
     #[inline]
     #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadU8"
+    }
+}
+
+impl Clone for PadU8 {
+    #[inline]
     fn clone(&self) -> Self {
         Self::VALUE
     }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
 }
 
 impl Copy for PadU8 {}
 
 impl Default for PadU8 {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::VALUE
     }
@@ -145,9 +546,17 @@ impl Hash for PadU8 {
     fn hash<H: Hasher>(&self, _: &mut H) {}
 }
 
+impl Index<usize> for PadU8 {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
 impl Ord for PadU8 {
     #[inline]
-    #[must_use]
     fn cmp(&self, _: &Self) -> Ordering {
         Ordering::Equal
     }
@@ -155,11 +564,11 @@ impl Ord for PadU8 {
 
 impl Pad for PadU8 {
     const VALUE: Self = Self(PadU8Inner::Value);
+    const HAS_NICHE: bool = true;
 }
 
 impl PartialEq for PadU8 {
     #[inline]
-    #[must_use]
     fn eq(&self, _: &Self) -> bool {
         true
     }
@@ -167,7 +576,6 @@ impl PartialEq for PadU8 {
 
 impl PartialOrd for PadU8 {
     #[inline]
-    #[must_use]
     fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
         Some(Ordering::Equal)
     }
@@ -181,7 +589,6 @@ enum PadU8Inner {
 
 impl Clone for PadU8Inner {
     #[inline]
-    #[must_use]
     fn clone(&self) -> Self {
         Self::Value
     }
@@ -197,19 +604,29 @@ impl Copy for PadU8Inner {}
 #[repr(transparent)]
 pub struct PadU16(PadU16Inner);
 
-impl Clone for PadU16 {
+impl PadU16 {
+    /// Returns the name of this padding type.
     #[inline]
     #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadU16"
+    }
+}
+
+impl Clone for PadU16 {
+    #[inline]
     fn clone(&self) -> Self {
         Self::VALUE
     }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
 }
 
 impl Copy for PadU16 {}
 
 impl Default for PadU16 {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::VALUE
     }
@@ -222,9 +639,17 @@ impl Hash for PadU16 {
     fn hash<H: Hasher>(&self, _: &mut H) {}
 }
 
+impl Index<usize> for PadU16 {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
 impl Ord for PadU16 {
     #[inline]
-    #[must_use]
     fn cmp(&self, _: &Self) -> Ordering {
         Ordering::Equal
     }
@@ -232,11 +657,11 @@ impl Ord for PadU16 {
 
 impl Pad for PadU16 {
     const VALUE: Self = Self(PadU16Inner::Value);
+    const HAS_NICHE: bool = true;
 }
 
 impl PartialEq for PadU16 {
     #[inline]
-    #[must_use]
     fn eq(&self, _: &Self) -> bool {
         true
     }
@@ -244,7 +669,6 @@ impl PartialEq for PadU16 {
 
 impl PartialOrd for PadU16 {
     #[inline]
-    #[must_use]
     fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
         Some(Ordering::Equal)
     }
@@ -258,7 +682,6 @@ enum PadU16Inner {
 
 impl Clone for PadU16Inner {
     #[inline]
-    #[must_use]
     fn clone(&self) -> Self {
         Self::Value
     }
@@ -274,19 +697,29 @@ impl Copy for PadU16Inner {}
 #[repr(transparent)]
 pub struct PadU32(PadU32Inner);
 
-impl Clone for PadU32 {
+impl PadU32 {
+    /// Returns the name of this padding type.
     #[inline]
     #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadU32"
+    }
+}
+
+impl Clone for PadU32 {
+    #[inline]
     fn clone(&self) -> Self {
         Self::VALUE
     }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
 }
 
 impl Copy for PadU32 {}
 
 impl Default for PadU32 {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::VALUE
     }
@@ -299,9 +732,17 @@ impl Hash for PadU32 {
     fn hash<H: Hasher>(&self, _: &mut H) {}
 }
 
+impl Index<usize> for PadU32 {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
 impl Ord for PadU32 {
     #[inline]
-    #[must_use]
     fn cmp(&self, _: &Self) -> Ordering {
         Ordering::Equal
     }
@@ -309,11 +750,11 @@ impl Ord for PadU32 {
 
 impl Pad for PadU32 {
     const VALUE: Self = Self(PadU32Inner::Value);
+    const HAS_NICHE: bool = true;
 }
 
 impl PartialEq for PadU32 {
     #[inline]
-    #[must_use]
     fn eq(&self, _: &Self) -> bool {
         true
     }
@@ -321,7 +762,6 @@ impl PartialEq for PadU32 {
 
 impl PartialOrd for PadU32 {
     #[inline]
-    #[must_use]
     fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
         Some(Ordering::Equal)
     }
@@ -335,7 +775,6 @@ enum PadU32Inner {
 
 impl Clone for PadU32Inner {
     #[inline]
-    #[must_use]
     fn clone(&self) -> Self {
         Self::Value
     }
@@ -347,23 +786,37 @@ impl Copy for PadU32Inner {}
 ///
 /// `PadU64` is implemented as a wrapper around a single-variant enum
 /// with an all-zeros bit-pattern.
+///
+/// The enum is `#[repr(u64)]`, so its alignment is that of `u64` on the
+/// current target, which is 4 rather than 8 on some 32-bit ABIs. Use
+/// [`PadWord`] for an alignment of 8 on every target.
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PadU64(PadU64Inner);
 
-impl Clone for PadU64 {
+impl PadU64 {
+    /// Returns the name of this padding type.
     #[inline]
     #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadU64"
+    }
+}
+
+impl Clone for PadU64 {
+    #[inline]
     fn clone(&self) -> Self {
         Self::VALUE
     }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
 }
 
 impl Copy for PadU64 {}
 
 impl Default for PadU64 {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::VALUE
     }
@@ -376,9 +829,17 @@ impl Hash for PadU64 {
     fn hash<H: Hasher>(&self, _: &mut H) {}
 }
 
+impl Index<usize> for PadU64 {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
 impl Ord for PadU64 {
     #[inline]
-    #[must_use]
     fn cmp(&self, _: &Self) -> Ordering {
         Ordering::Equal
     }
@@ -386,11 +847,11 @@ impl Ord for PadU64 {
 
 impl Pad for PadU64 {
     const VALUE: Self = Self(PadU64Inner::Value);
+    const HAS_NICHE: bool = true;
 }
 
 impl PartialEq for PadU64 {
     #[inline]
-    #[must_use]
     fn eq(&self, _: &Self) -> bool {
         true
     }
@@ -398,7 +859,6 @@ impl PartialEq for PadU64 {
 
 impl PartialOrd for PadU64 {
     #[inline]
-    #[must_use]
     fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
         Some(Ordering::Equal)
     }
@@ -412,7 +872,6 @@ enum PadU64Inner {
 
 impl Clone for PadU64Inner {
     #[inline]
-    #[must_use]
     fn clone(&self) -> Self {
         Self::Value
     }
@@ -420,81 +879,1842 @@ impl Clone for PadU64Inner {
 
 impl Copy for PadU64Inner {}
 
-/// A padding type with the same layout as `usize`.
-///
-/// `PadUsize` is a type alias to whichever padding type is
-/// the same size as `usize`.
-#[cfg(target_pointer_width = "16")]
-pub type PadUsize = PadU16;
-/// A padding type with the same layout as `usize`.
+/// A padding type with the same layout as `[u8; N]`.
 ///
-/// `PadUsize` is a type alias to whichever padding type is
-/// the same size as `usize`.
-#[cfg(target_pointer_width = "32")]
-pub type PadUsize = PadU32;
-/// A padding type with the same layout as `usize`.
+/// `PadBytes` is implemented as a transparent wrapper around an array
+/// of [`PadU8`], and is useful for padding regions which are larger
+/// than the builtin padding types.
 ///
-/// `PadUsize` is a type alias to whichever padding type is
-/// the same size as `usize`.
-#[cfg(target_pointer_width = "64")]
-pub type PadUsize = PadU64;
+/// Whatever its length, `PadBytes` has an alignment of 1, so that it may
+/// be placed at any offset, including within `#[repr(packed)]` structs.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct PadBytes<const N: usize>([PadU8; N]);
 
-mod private {
-    pub use super::*;
-    pub trait Sealed {}
-    impl Sealed for PadU0 {}
-    impl Sealed for PadU8 {}
-    impl Sealed for PadU16 {}
-    impl Sealed for PadU32 {}
-    impl Sealed for PadU64 {}
+impl<const N: usize> PadBytes<N> {
+    /// Returns the name of this padding type.
+    ///
+    /// The name does not include the length `N`.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadBytes"
+    }
+
+    const ZERO_BYTES: &'static [u8; N] = &[0; N];
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::mem::{align_of, size_of};
+impl<const N: usize> Clone for PadBytes<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
 
-    #[test]
-    fn align() {
-        assert_eq!(align_of::<PadU0>(), align_of::<()>());
-        assert_eq!(align_of::<PadU8>(), align_of::<u8>());
-        assert_eq!(align_of::<PadU16>(), align_of::<u16>());
-        assert_eq!(align_of::<PadU32>(), align_of::<u32>());
-        assert_eq!(align_of::<PadU64>(), align_of::<u64>());
-        assert_eq!(align_of::<PadUsize>(), align_of::<usize>());
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl<const N: usize> Copy for PadBytes<N> {}
+
+impl<const N: usize> Default for PadBytes<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
     }
+}
 
-    #[test]
-    fn align_option() {
-        assert_eq!(align_of::<Option<PadU0>>(), align_of::<Option<()>>());
-        assert_eq!(align_of::<Option<PadU8>>(), align_of::<u8>());
-        assert_eq!(align_of::<Option<PadU16>>(), align_of::<u16>());
-        assert_eq!(align_of::<Option<PadU32>>(), align_of::<u32>());
-        assert_eq!(align_of::<Option<PadU64>>(), align_of::<u64>());
-        assert_eq!(align_of::<Option<PadUsize>>(), align_of::<usize>());
+impl<const N: usize> Eq for PadBytes<N> {}
+
+impl<const N: usize> Hash for PadBytes<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<const N: usize> Index<usize> for PadBytes<N> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
     }
+}
 
-    #[test]
-    fn size() {
-        assert_eq!(size_of::<PadU0>(), size_of::<()>());
-        assert_eq!(size_of::<PadU8>(), size_of::<u8>());
-        assert_eq!(size_of::<PadU16>(), size_of::<u16>());
-        assert_eq!(size_of::<PadU32>(), size_of::<u32>());
-        assert_eq!(size_of::<PadU64>(), size_of::<u64>());
-        assert_eq!(size_of::<PadUsize>(), size_of::<usize>());
+impl<const N: usize> Ord for PadBytes<N> {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
     }
+}
 
-    #[test]
-    fn size_option() {
-        assert_eq!(size_of::<Option<PadU0>>(), size_of::<Option<()>>());
-        assert_eq!(size_of::<Option<PadU8>>(), size_of::<u8>());
-        assert_eq!(size_of::<Option<PadU16>>(), size_of::<u16>());
-        assert_eq!(size_of::<Option<PadU32>>(), size_of::<u32>());
-        assert_eq!(size_of::<Option<PadU64>>(), size_of::<u64>());
-        assert_eq!(size_of::<Option<PadUsize>>(), size_of::<usize>());
+impl<const N: usize> Pad for PadBytes<N> {
+    const VALUE: Self = Self([PadU8::VALUE; N]);
+    const HAS_NICHE: bool = N > 0;
+
+    #[inline]
+    fn bytes(&self) -> &'static [u8] {
+        Self::ZERO_BYTES
     }
+}
 
-    #[test]
+impl<const N: usize> PartialEq for PadBytes<N> {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> PartialOrd for PadBytes<N> {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// A zero-sized padding type marking a flexible array member.
+///
+/// In C, a struct may end with a flexible array member, whose elements
+/// follow the struct in memory. `PadFlexible` documents that intent in
+/// the corresponding Rust struct: it marks where the dynamically sized
+/// tail begins, without adding any size or alignment.
+///
+/// `PadFlexible` has the same layout as [`PadU0`]; the difference
+/// between the two is purely semantic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct PadFlexible([u8; 0]);
+
+impl PadFlexible {
+    /// Returns the name of this padding type.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadFlexible"
+    }
+}
+
+impl Clone for PadFlexible {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl Copy for PadFlexible {}
+
+impl Default for PadFlexible {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl Eq for PadFlexible {}
+
+impl Hash for PadFlexible {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Index<usize> for PadFlexible {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl Ord for PadFlexible {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Pad for PadFlexible {
+    const VALUE: Self = Self([]);
+    const HAS_NICHE: bool = false;
+}
+
+impl PartialEq for PadFlexible {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for PadFlexible {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// A zero-sized padding type carrying a [`PhantomData<T>`].
+///
+/// Unlike [`PadU0`], `PadMarker<T>` inherits the variance and auto traits
+/// of `T`, like `PhantomData<T>` does. It may therefore replace a
+/// `PhantomData` field while still implementing [`Pad`]. In particular,
+/// `PadMarker<T>` is only `Send` and `Sync` if `T` is.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{Pad, PadMarker};
+///
+/// #[repr(C)]
+/// struct Handle<T> {
+///     id: u32,
+///     marker: PadMarker<T>,
+/// }
+///
+/// let handle = Handle::<String> {
+///     id: 7,
+///     marker: Pad::VALUE,
+/// };
+/// assert_eq!(core::mem::size_of_val(&handle), 4);
+/// ```
+///
+/// ```compile_fail
+/// use struct_pad::PadMarker;
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<PadMarker<*const u8>>();
+/// ```
+///
+/// [`PhantomData<T>`]: PhantomData
+#[repr(C)]
+pub struct PadMarker<T: ?Sized>([u8; 0], PhantomData<T>);
+
+impl<T: ?Sized> PadMarker<T> {
+    /// Returns the name of this padding type.
+    ///
+    /// The name does not include the type parameter `T`.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadMarker"
+    }
+}
+
+impl<T: ?Sized> Clone for PadMarker<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl<T: ?Sized> Copy for PadMarker<T> {}
+
+impl<T: ?Sized> fmt::Debug for PadMarker<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PadMarker")
+    }
+}
+
+impl<T: ?Sized> Default for PadMarker<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl<T: ?Sized> Eq for PadMarker<T> {}
+
+impl<T: ?Sized> Hash for PadMarker<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<T: ?Sized> Index<usize> for PadMarker<T> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl<T: ?Sized> Ord for PadMarker<T> {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T: ?Sized> Pad for PadMarker<T> {
+    const VALUE: Self = Self([], PhantomData);
+    const HAS_NICHE: bool = false;
+}
+
+impl<T: ?Sized> PartialEq for PadMarker<T> {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> PartialOrd for PadMarker<T> {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// A zero-sized padding type with an alignment of 16 bytes.
+///
+/// `PadSimd128` raises the alignment of a struct to that of 128-bit
+/// SIMD vectors such as `__m128` or `f32x4`, without occupying any space.
+/// It is useful for scratch buffers which are loaded into SIMD registers.
+#[derive(Debug)]
+#[repr(C, align(16))]
+pub struct PadSimd128([u8; 0]);
+
+impl PadSimd128 {
+    /// Returns the name of this padding type.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadSimd128"
+    }
+}
+
+impl Clone for PadSimd128 {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl Copy for PadSimd128 {}
+
+impl Default for PadSimd128 {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl Eq for PadSimd128 {}
+
+impl Hash for PadSimd128 {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Index<usize> for PadSimd128 {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl Ord for PadSimd128 {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Pad for PadSimd128 {
+    const VALUE: Self = Self([]);
+    const HAS_NICHE: bool = false;
+}
+
+impl PartialEq for PadSimd128 {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for PadSimd128 {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// A padding type with the same size and alignment as a 128-bit SIMD vector.
+///
+/// `PadSimd128Bytes` occupies 16 bytes with an alignment of 16 bytes,
+/// matching the layout of types such as `__m128` or `f32x4`.
+#[derive(Debug)]
+#[repr(C, align(16))]
+pub struct PadSimd128Bytes([PadU64; 2]);
+
+impl PadSimd128Bytes {
+    /// Returns the name of this padding type.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadSimd128Bytes"
+    }
+}
+
+impl Clone for PadSimd128Bytes {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl Copy for PadSimd128Bytes {}
+
+impl Default for PadSimd128Bytes {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl Eq for PadSimd128Bytes {}
+
+impl Hash for PadSimd128Bytes {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Index<usize> for PadSimd128Bytes {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl Ord for PadSimd128Bytes {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Pad for PadSimd128Bytes {
+    const VALUE: Self = Self([PadU64::VALUE; 2]);
+    const HAS_NICHE: bool = true;
+
+    #[inline]
+    fn bytes(&self) -> &'static [u8] {
+        &[0; 16]
+    }
+}
+
+impl PartialEq for PadSimd128Bytes {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for PadSimd128Bytes {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// A padding type occupying one 8-byte word.
+///
+/// `PadWord` has the same size as [`PadU64`], but is always 8-byte
+/// aligned, even on targets where `u64` is not. It names the intent of
+/// word-oriented layouts, such as those of Cap'n Proto.
+#[derive(Debug)]
+#[repr(C, align(8))]
+pub struct PadWord(PadU64);
+
+impl PadWord {
+    /// Returns the name of this padding type.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadWord"
+    }
+}
+
+impl Clone for PadWord {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl Copy for PadWord {}
+
+impl Default for PadWord {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl Eq for PadWord {}
+
+impl Hash for PadWord {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Index<usize> for PadWord {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl Ord for PadWord {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Pad for PadWord {
+    const VALUE: Self = Self(PadU64::VALUE);
+    const HAS_NICHE: bool = true;
+}
+
+impl PartialEq for PadWord {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for PadWord {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// A padding type occupying `N` 8-byte words.
+///
+/// `PadWords` has a size of `8 * N` bytes and an alignment of 8 bytes,
+/// like an array of [`PadWord`].
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct PadWords<const N: usize>([PadWord; N]);
+
+impl<const N: usize> PadWords<N> {
+    /// Returns the name of this padding type.
+    ///
+    /// The name does not include the length `N`.
+    #[inline]
+    #[must_use]
+    pub const fn type_name() -> &'static str {
+        "PadWords"
+    }
+
+    const ZERO_WORDS: &'static [[u8; 8]; N] = &[[0; 8]; N];
+}
+
+impl<const N: usize> Clone for PadWords<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::VALUE
+    }
+
+    #[inline]
+    fn clone_from(&mut self, _: &Self) {}
+}
+
+impl<const N: usize> Copy for PadWords<N> {}
+
+impl<const N: usize> Default for PadWords<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl<const N: usize> Eq for PadWords<N> {}
+
+impl<const N: usize> Hash for PadWords<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<const N: usize> Index<usize> for PadWords<N> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        index_zero(index, Self::SIZE)
+    }
+}
+
+impl<const N: usize> Ord for PadWords<N> {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<const N: usize> Pad for PadWords<N> {
+    const VALUE: Self = Self([PadWord::VALUE; N]);
+    const HAS_NICHE: bool = N > 0;
+
+    #[inline]
+    fn bytes(&self) -> &'static [u8] {
+        // SAFETY: `[[u8; 8]; N]` is `Self::SIZE` contiguous bytes, all of
+        // which are initialized.
+        unsafe { core::slice::from_raw_parts(Self::ZERO_WORDS.as_ptr().cast::<u8>(), Self::SIZE) }
+    }
+}
+
+impl<const N: usize> PartialEq for PadWords<N> {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> PartialOrd for PadWords<N> {
+    #[inline]
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// Expands to a padding type reserving the given number of bytes.
+///
+/// `reserved!` is intended for hardware register maps and wire formats,
+/// where reserved regions are usually described by a byte count and a
+/// short note. The note is only for readers of the source; since macros
+/// cannot expand to struct fields, a doc comment on the field itself is
+/// needed for the description to appear in generated documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{reserved, Pad};
+///
+/// #[repr(C)]
+/// struct Registers {
+///     control: u32,
+///     /// Reserved for the firmware revision.
+///     reserved0: reserved!(0x10, "firmware revision"),
+///     status: u32,
+/// }
+///
+/// let regs = Registers {
+///     control: 0,
+///     reserved0: Pad::VALUE,
+///     status: 0,
+/// };
+/// assert_eq!(core::mem::size_of_val(&regs), 24);
+/// ```
+#[macro_export]
+macro_rules! reserved {
+    ($size:expr $(,)?) => {
+        $crate::PadBytes<{ $size }>
+    };
+    ($size:expr, $note:literal $(,)?) => {
+        $crate::PadBytes<{ $size }>
+    };
+}
+
+/// Expands to a C declaration of a reserved field of the given number of bytes.
+///
+/// The expansion is a `&'static str` such as `"uint8_t reserved[16];"`,
+/// intended for code generators that keep a C header in sync with a Rust
+/// struct using [`reserved!`]. The field name defaults to `reserved`.
+/// The size must be an integer literal.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::c_reserved;
+///
+/// assert_eq!(c_reserved!(16), "uint8_t reserved[16];");
+/// assert_eq!(c_reserved!(0x10, reserved0), "uint8_t reserved0[16];");
+/// ```
+#[macro_export]
+macro_rules! c_reserved {
+    ($size:literal $(,)?) => {
+        $crate::c_reserved!($size, reserved)
+    };
+    ($size:literal, $name:ident $(,)?) => {
+        concat!("uint8_t ", stringify!($name), "[", $size, "];")
+    };
+}
+
+/// Expands to the padding type standing in for a field of the given type.
+///
+/// When evolving a wire format, reserved fields are often later promoted
+/// to real fields. Writing a reserved field as `reserved_field!(u32)`
+/// documents its intended future type, so that the promotion is a matter
+/// of replacing the macro invocation with `u32`. The type must implement
+/// [`HasPad`]. As with [`reserved!`], macros cannot expand to struct
+/// fields, so the field name is written as usual.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{reserved_field, Pad};
+///
+/// #[repr(C)]
+/// struct Header {
+///     version: u16,
+///     flags: reserved_field!(u16),
+///     len: u32,
+/// }
+///
+/// let header = Header {
+///     version: 1,
+///     flags: Pad::VALUE,
+///     len: 0,
+/// };
+/// assert_eq!(core::mem::size_of_val(&header), 8);
+/// ```
+#[macro_export]
+macro_rules! reserved_field {
+    ($ty:ty $(,)?) => {
+        $crate::PadMatching<$ty>
+    };
+}
+
+/// Expands to an array describing the layout of the named fields of a struct.
+///
+/// Each element of the array is an `(offset, size)` pair in bytes, in the
+/// order the fields are named. The expansion is a constant expression,
+/// so it may be used to initialize a `const` or `static`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{layout_report, PadU16, PadU8};
+///
+/// #[repr(C)]
+/// struct Example {
+///     a: u8,
+///     pad1: PadU8,
+///     pad2: PadU16,
+///     b: u32,
+/// }
+///
+/// const REPORT: [(usize, usize); 4] = layout_report!(Example { a, pad1, pad2, b });
+/// assert_eq!(REPORT, [(0, 1), (1, 1), (2, 2), (4, 4)]);
+/// ```
+#[macro_export]
+macro_rules! layout_report {
+    ($ty:ty { $($field:tt),* $(,)? }) => {
+        [$((
+            ::core::mem::offset_of!($ty, $field),
+            $crate::__private::field_size(|value: &$ty| &value.$field),
+        )),*]
+    };
+}
+
+/// Asserts at compile time that two types have the same size and alignment.
+///
+/// This is useful when replacing the implicit padding of a struct with
+/// explicit padding fields, to check that the layout has not changed.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{assert_layout_eq, PadU16, PadU8};
+///
+/// #[repr(C)]
+/// struct Old {
+///     a: u8,
+///     b: u32,
+/// }
+///
+/// #[repr(C)]
+/// struct New {
+///     a: u8,
+///     pad1: PadU8,
+///     pad2: PadU16,
+///     b: u32,
+/// }
+///
+/// assert_layout_eq!(Old, New);
+/// ```
+///
+/// Layouts which differ fail to compile:
+///
+/// ```compile_fail
+/// use struct_pad::{assert_layout_eq, PadU8};
+///
+/// #[repr(C)]
+/// struct Old {
+///     a: u8,
+///     b: u32,
+/// }
+///
+/// #[repr(C)]
+/// struct New {
+///     a: u8,
+///     pad1: PadU8,
+///     b: u32,
+///     pad2: PadU8,
+/// }
+///
+/// assert_layout_eq!(Old, New);
+/// ```
+#[macro_export]
+macro_rules! assert_layout_eq {
+    ($old:ty, $new:ty $(,)?) => {
+        const _: () = {
+            assert!(
+                ::core::mem::size_of::<$old>() == ::core::mem::size_of::<$new>(),
+                concat!(
+                    "size of `",
+                    stringify!($old),
+                    "` differs from `",
+                    stringify!($new),
+                    "`"
+                ),
+            );
+            assert!(
+                ::core::mem::align_of::<$old>() == ::core::mem::align_of::<$new>(),
+                concat!(
+                    "alignment of `",
+                    stringify!($old),
+                    "` differs from `",
+                    stringify!($new),
+                    "`"
+                ),
+            );
+        };
+    };
+}
+
+/// Asserts at compile time that a padding type has the same size as the
+/// type it reserves space for.
+///
+/// This is intended for FFI, where the second type is the Rust mirror of a
+/// C type, such as those in `core::ffi`.
+///
+/// # Example
+///
+/// ```rust
+/// use core::ffi::{c_char, c_int};
+/// use struct_pad::{assert_pad_matches, PadU32, PadU8};
+///
+/// assert_pad_matches!(PadU32, c_int);
+/// assert_pad_matches!(PadU8, c_char);
+/// ```
+///
+/// Sizes which differ fail to compile:
+///
+/// ```compile_fail
+/// use core::ffi::c_int;
+/// use struct_pad::{assert_pad_matches, PadU16};
+///
+/// assert_pad_matches!(PadU16, c_int);
+/// ```
+#[macro_export]
+macro_rules! assert_pad_matches {
+    ($pad:ty, $ty:ty $(,)?) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$pad>() == ::core::mem::size_of::<$ty>(),
+            concat!(
+                "size of `",
+                stringify!($pad),
+                "` differs from `",
+                stringify!($ty),
+                "`"
+            ),
+        );
+    };
+}
+
+/// A padding type with the same layout as `usize`.
+///
+/// `PadUsize` is a type alias to whichever padding type is
+/// the same size as `usize`.
+#[cfg(target_pointer_width = "16")]
+pub type PadUsize = PadU16;
+/// A padding type with the same layout as `usize`.
+///
+/// `PadUsize` is a type alias to whichever padding type is
+/// the same size as `usize`.
+#[cfg(target_pointer_width = "32")]
+pub type PadUsize = PadU32;
+/// A padding type with the same layout as `usize`.
+///
+/// `PadUsize` is a type alias to whichever padding type is
+/// the same size as `usize`.
+#[cfg(target_pointer_width = "64")]
+pub type PadUsize = PadU64;
+
+// Pin the layout of `PadUsize` on every target width. `usize` itself has
+// no niche, so `Option<usize>` is larger than `usize`; `Option<PadUsize>`
+// instead matches `Option<NonZeroUsize>`.
+const _: () = {
+    use core::num::NonZeroUsize;
+    assert!(size_of::<PadUsize>() == size_of::<usize>());
+    assert!(align_of::<PadUsize>() == align_of::<usize>());
+    assert!(size_of::<Option<PadUsize>>() == size_of::<usize>());
+    assert!(size_of::<Option<PadUsize>>() == size_of::<Option<NonZeroUsize>>());
+};
+
+/// The size in bytes of the largest builtin padding type.
+///
+/// The builtin padding types are the fixed-size `PadU*` family, i.e. those
+/// listed in [`BUILTIN_PADS`]. Wider special-purpose types, such as the
+/// 16-byte [`PadSimd128Bytes`], are not builtin in this sense. Padding
+/// regions larger than this must be composed from several padding fields.
+pub const MAX_BUILTIN_PAD_SIZE: usize = 8;
+
+/// Returns `true` if a builtin padding type of `n` bytes exists.
+///
+/// As with [`MAX_BUILTIN_PAD_SIZE`], only the `PadU*` family listed in
+/// [`BUILTIN_PADS`] is considered, so this is `false` for 16 bytes despite
+/// [`PadSimd128Bytes`]. Padding regions of any other size must be composed
+/// from several padding fields, or declared with [`PadBytes`].
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::is_builtin_pad_size;
+///
+/// const _: () = assert!(is_builtin_pad_size(4));
+/// assert!(!is_builtin_pad_size(3));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_builtin_pad_size(n: usize) -> bool {
+    matches!(n, 0 | 1 | 2 | 4 | 8)
+}
+
+/// Returns `true` if every byte of `bytes` is zero.
+///
+/// Since this is a `const fn`, it may be used to check at compile time
+/// that the reserved fields of a static table have been zeroed.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::all_zero;
+///
+/// static RESERVED: [u8; 4] = [0; 4];
+/// const _: () = assert!(all_zero(&RESERVED));
+/// assert!(!all_zero(&[0, 1]));
+/// ```
+#[must_use]
+pub const fn all_zero(bytes: &[u8]) -> bool {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Writes [`P::VALUE`](Pad::VALUE) to `dst`, without reading or dropping
+/// the old value.
+///
+/// This is intended for constructing structs in place, for instance
+/// through a [`MaybeUninit`](core::mem::MaybeUninit) or a raw allocation,
+/// where the padding fields must be initialized one by one.
+///
+/// # Safety
+///
+/// `dst` must be [valid] for writes, and properly aligned.
+///
+/// [valid]: core::ptr#safety
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use struct_pad::{write_pad, Pad, PadU32};
+///
+/// let mut slot = MaybeUninit::<PadU32>::uninit();
+/// // SAFETY: `slot` is valid for writes, and properly aligned.
+/// let pad = unsafe {
+///     write_pad(slot.as_mut_ptr());
+///     slot.assume_init()
+/// };
+/// assert_eq!(pad, PadU32::VALUE);
+/// ```
+#[inline]
+pub unsafe fn write_pad<P: Pad>(dst: *mut P) {
+    // SAFETY: the caller upholds the requirements of `ptr::write`.
+    unsafe { dst.write(P::VALUE) }
+}
+
+/// Returns `true` if the size of `P` is a multiple of its alignment.
+///
+/// Padding types are meant to fill gaps, so they must never introduce
+/// padding of their own. Every Rust type satisfies this invariant, and it
+/// is checked at compile time for every padding type of this crate.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{pad_is_gapless, PadU64};
+///
+/// const _: () = assert!(pad_is_gapless::<PadU64>());
+/// ```
+#[inline]
+#[must_use]
+pub const fn pad_is_gapless<P: Pad>() -> bool {
+    P::SIZE % P::ALIGN == 0
+}
+
+// Check the invariants documented on `pad_is_gapless` and `is_well_formed`,
+// rejecting malformed padding type definitions at compile time.
+const _: () = {
+    macro_rules! check {
+        ($($ty:ty),*) => {
+            $(
+                assert!(pad_is_gapless::<$ty>());
+                assert!(is_well_formed(<$ty as Pad>::SIZE, <$ty as Pad>::ALIGN));
+            )*
+        };
+    }
+
+    check!(
+        PadU0,
+        PadFlexible,
+        PadMarker<()>,
+        PadU8,
+        PadU16,
+        PadU32,
+        PadU64,
+        PadBytes<0>,
+        PadBytes<3>,
+        PadSimd128,
+        PadSimd128Bytes,
+        PadWord,
+        PadWords<0>,
+        PadWords<3>
+    );
+};
+
+/// Returns `true` if `Option<T>` has the same size as `T`.
+///
+/// The result agrees with [`Pad::HAS_NICHE`], but is computed from the
+/// actual layout, so it may be used in a constant assertion to verify that
+/// the niche optimization applies.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{has_option_niche, PadU0, PadU32};
+///
+/// const _: () = assert!(has_option_niche::<PadU32>());
+/// assert!(!has_option_niche::<PadU0>());
+/// ```
+#[inline]
+#[must_use]
+pub const fn has_option_niche<T: Pad>() -> bool {
+    size_of::<Option<T>>() == size_of::<T>()
+}
+
+/// Returns `true` if `P` exactly fills a gap of `gap` bytes.
+///
+/// This may be used in a constant assertion, to check that the padding
+/// type chosen for a computed gap leaves no slack and does not overflow it.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{pad_fills_gap, struct_size, PadU32};
+///
+/// // struct { a: u32, b: u64 }
+/// const GAP: usize = struct_size(&[(4, 4), (8, 8)]) - 4 - 8;
+/// const _: () = assert!(pad_fills_gap::<PadU32>(GAP));
+/// ```
+#[inline]
+#[must_use]
+pub const fn pad_fills_gap<P: Pad>(gap: usize) -> bool {
+    P::SIZE == gap
+}
+
+/// Returns `true` if `P` has a nonzero size.
+///
+/// Macros which pick a padding type for a computed gap may produce
+/// [`PadU0`] when the gap turns out to be empty. Where the gap is known to
+/// be nonzero, a zero-sized pad silently fails to fill it, so such macros
+/// may guard each padding field with a constant assertion.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{expect_nonzero_pad, PadU8};
+///
+/// macro_rules! filler {
+///     ($pad:ty) => {
+///         const _: () = assert!(expect_nonzero_pad::<$pad>(), "empty filler");
+///     };
+/// }
+///
+/// filler!(PadU8);
+/// ```
+///
+/// A zero-sized pad fails to compile:
+///
+/// ```compile_fail
+/// use struct_pad::{expect_nonzero_pad, PadU0};
+///
+/// const _: () = assert!(expect_nonzero_pad::<PadU0>());
+/// ```
+#[inline]
+#[must_use]
+pub const fn expect_nonzero_pad<P: Pad>() -> bool {
+    P::SIZE > 0
+}
+
+/// Returns the number of bytes taken up by `count` [`PadU0`] markers,
+/// which is always zero.
+///
+/// `PadU0` is zero-sized with an alignment of 1, so any number of them may
+/// be stacked in a struct without changing its size or alignment. This
+/// function exists to document that guarantee at the point of use.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::size_of;
+/// use struct_pad::{zst_cost, PadU0};
+///
+/// assert_eq!(size_of::<[PadU0; 100]>(), zst_cost(100));
+/// ```
+#[inline]
+#[must_use]
+pub const fn zst_cost(_count: usize) -> usize {
+    0
+}
+
+/// Returns the size and alignment of `P`, as a `(size, align)` pair.
+///
+/// This is the field descriptor format used by the layout helpers such as
+/// [`struct_size`], and is intended for build scripts which emit layout
+/// metadata for bindings in other languages. Note that a build script is
+/// compiled for the host, so an alignment which varies between targets,
+/// such as that of [`PadU64`], describes the host rather than the target.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{describe_pad, PadU16, PadU8};
+///
+/// const PADS: [(&str, (usize, usize)); 2] = [
+///     ("PadU8", describe_pad::<PadU8>()),
+///     ("PadU16", describe_pad::<PadU16>()),
+/// ];
+/// for (name, (size, align)) in PADS {
+///     println!(r#"{{"name":"{}","size":{},"align":{}}}"#, name, size, align);
+/// }
+/// ```
+#[inline]
+#[must_use]
+pub const fn describe_pad<P: Pad>() -> (usize, usize) {
+    (P::SIZE, P::ALIGN)
+}
+
+/// Returns the larger of the sizes of `A` and `B`.
+///
+/// Together with [`max_align`], this gives the layout of the region a
+/// union of `A` and `B` needs to reserve.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{max_size, PadU16, PadU32};
+///
+/// assert_eq!(max_size::<PadU16, PadU32>(), 4);
+/// ```
+#[inline]
+#[must_use]
+pub const fn max_size<A: Pad, B: Pad>() -> usize {
+    if A::SIZE > B::SIZE {
+        A::SIZE
+    } else {
+        B::SIZE
+    }
+}
+
+/// Returns the larger of the alignments of `A` and `B`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{max_align, PadU64, PadU8};
+///
+/// assert_eq!(max_align::<PadU64, PadU8>(), core::mem::align_of::<u64>());
+/// ```
+#[inline]
+#[must_use]
+pub const fn max_align<A: Pad, B: Pad>() -> usize {
+    if A::ALIGN > B::ALIGN {
+        A::ALIGN
+    } else {
+        B::ALIGN
+    }
+}
+
+/// Zeroes the bytes of `buf` from `used` up to `target`.
+///
+/// This is the runtime analog of trailing padding fields: after writing
+/// `used` bytes of a record into an I/O buffer, the reserved tail up to
+/// `target` is cleared.
+///
+/// # Panics
+///
+/// Panics if `target` is greater than the length of `buf`,
+/// or if `used` is greater than `target`.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::pad_slice_to;
+///
+/// let mut buf = [0xff; 8];
+/// buf[..3].copy_from_slice(b"abc");
+/// pad_slice_to(&mut buf, 3, 6);
+/// assert_eq!(buf, [b'a', b'b', b'c', 0, 0, 0, 0xff, 0xff]);
+/// ```
+#[inline]
+pub fn pad_slice_to(buf: &mut [u8], used: usize, target: usize) {
+    assert!(
+        target <= buf.len(),
+        "target length {} exceeds the buffer length {}",
+        target,
+        buf.len()
+    );
+    for byte in &mut buf[used..target] {
+        *byte = 0;
+    }
+}
+
+/// Returns the size of the smallest builtin padding type
+/// with an alignment of `align` bytes.
+///
+/// Since the size of a padding type is a multiple of its alignment,
+/// such a padding field raises the alignment of a struct to `align`.
+/// The [`pad_for_align!`] macro names the corresponding type.
+///
+/// # Panics
+///
+/// Panics if no builtin padding type has an alignment of `align`.
+/// This is always the case for alignments greater than 8, and may also
+/// be the case for an alignment of 8 on targets where `u64` has a lower
+/// alignment, such as 32-bit x86.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::pad_size_for_align;
+///
+/// assert_eq!(pad_size_for_align(2), 2);
+/// assert_eq!(pad_size_for_align(4), 4);
+/// ```
+#[must_use]
+pub const fn pad_size_for_align(align: usize) -> usize {
+    if align == align_of::<PadU8>() {
+        size_of::<PadU8>()
+    } else if align == align_of::<PadU16>() {
+        size_of::<PadU16>()
+    } else if align == align_of::<PadU32>() {
+        size_of::<PadU32>()
+    } else if align == align_of::<PadU64>() {
+        size_of::<PadU64>()
+    } else {
+        panic!("no builtin padding type has the requested alignment")
+    }
+}
+
+/// Expands to the smallest builtin padding type with the given alignment.
+///
+/// See [`pad_size_for_align`] for the supported alignments. An unsupported
+/// alignment is a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::align_of;
+/// use struct_pad::{pad_for_align, Pad};
+///
+/// #[repr(C)]
+/// struct Aligned {
+///     value: [u8; 3],
+///     align: pad_for_align!(4),
+/// }
+///
+/// assert_eq!(align_of::<Aligned>(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use struct_pad::{pad_for_align, Pad};
+///
+/// let pad: pad_for_align!(3) = Pad::VALUE;
+/// ```
+#[macro_export]
+macro_rules! pad_for_align {
+    ($align:expr $(,)?) => {
+        <$crate::__private::Size<{ $crate::pad_size_for_align($align) }> as $crate::__private::PadOfSize>::Pad
+    };
+}
+
+/// Expands to a tuple of the [`VALUE`](Pad::VALUE)s of the given padding types.
+///
+/// The expansion is a constant expression, so it may be used to initialize
+/// a `const` or `static` in test fixtures. The type of such an item must be
+/// spelled out, as the tuple of the padding types given to the macro.
+///
+/// Note that the layout of a tuple is unspecified, so its size may include
+/// trailing padding beyond the sum of the sizes of its elements.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{all_pads, PadU16, PadU32, PadU8};
+///
+/// const FIXTURE: (PadU32, PadU8, PadU16) = all_pads!(PadU32, PadU8, PadU16);
+/// assert_eq!(FIXTURE, Default::default());
+/// ```
+#[macro_export]
+macro_rules! all_pads {
+    ($($ty:ty),* $(,)?) => {
+        ($(<$ty as $crate::Pad>::VALUE,)*)
+    };
+}
+
+// Pin the thread-safety guarantee documented at the crate root.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<PadU0>();
+    assert_send_sync::<PadFlexible>();
+    assert_send_sync::<PadMarker<u32>>();
+    assert_send_sync::<PadU8>();
+    assert_send_sync::<PadU16>();
+    assert_send_sync::<PadU32>();
+    assert_send_sync::<PadU64>();
+    assert_send_sync::<PadBytes<1>>();
+    assert_send_sync::<PadSimd128>();
+    assert_send_sync::<PadSimd128Bytes>();
+    assert_send_sync::<PadWord>();
+    assert_send_sync::<PadWords<1>>();
+    assert_send_sync::<PadUsize>();
+    assert_send_sync::<ZeroedPad<PadU32>>();
+    assert_send_sync::<DontCarePad<PadU32>>();
+    assert_send_sync::<AnyPad>();
+};
+
+// Backs `Pad::bytes` for the padding types no larger than the builtins.
+static ZEROS: [u8; MAX_BUILTIN_PAD_SIZE] = [0; MAX_BUILTIN_PAD_SIZE];
+
+// Indexes into `len` zero bytes.
+#[inline]
+fn index_zero(index: usize, len: usize) -> &'static u8 {
+    if index < len {
+        &0
+    } else {
+        panic!(
+            "index out of bounds: the len is {} but the index is {}",
+            len, index
+        )
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    pub struct Size<const N: usize>;
+
+    #[inline]
+    pub const fn field_size<T, F>(_: fn(&T) -> &F) -> usize {
+        size_of::<F>()
+    }
+
+    pub trait PadOfSize {
+        type Pad: Pad;
+    }
+
+    impl PadOfSize for Size<1> {
+        type Pad = PadU8;
+    }
+
+    impl PadOfSize for Size<2> {
+        type Pad = PadU16;
+    }
+
+    impl PadOfSize for Size<4> {
+        type Pad = PadU32;
+    }
+
+    impl PadOfSize for Size<8> {
+        type Pad = PadU64;
+    }
+}
+
+mod private {
+    pub use super::*;
+    pub trait Sealed {}
+    impl Sealed for PadU0 {}
+    impl Sealed for PadFlexible {}
+    impl<T: ?Sized> Sealed for PadMarker<T> {}
+    impl Sealed for PadU8 {}
+    impl Sealed for PadU16 {}
+    impl Sealed for PadU32 {}
+    impl Sealed for PadU64 {}
+    impl<const N: usize> Sealed for PadBytes<N> {}
+    impl Sealed for PadSimd128 {}
+    impl Sealed for PadSimd128Bytes {}
+    impl Sealed for PadWord {}
+    impl<const N: usize> Sealed for PadWords<N> {}
+    impl<P: Pad> Sealed for ZeroedPad<P> {}
+    impl<P: Pad> Sealed for DontCarePad<P> {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of, size_of_val};
+
+    #[test]
+    fn align() {
+        assert_eq!(align_of::<PadU0>(), align_of::<()>());
+        assert_eq!(align_of::<PadFlexible>(), align_of::<()>());
+        assert_eq!(align_of::<PadU8>(), align_of::<u8>());
+        assert_eq!(align_of::<PadU16>(), align_of::<u16>());
+        assert_eq!(align_of::<PadU32>(), align_of::<u32>());
+        assert_eq!(align_of::<PadU64>(), align_of::<u64>());
+        assert_eq!(align_of::<PadBytes<3>>(), align_of::<[u8; 3]>());
+        assert_eq!(align_of::<PadUsize>(), align_of::<usize>());
+    }
+
+    #[test]
+    fn align_option() {
+        assert_eq!(align_of::<Option<PadU0>>(), align_of::<Option<()>>());
+        assert_eq!(align_of::<Option<PadFlexible>>(), align_of::<Option<()>>());
+        assert_eq!(align_of::<Option<PadU8>>(), align_of::<u8>());
+        assert_eq!(align_of::<Option<PadU16>>(), align_of::<u16>());
+        assert_eq!(align_of::<Option<PadU32>>(), align_of::<u32>());
+        assert_eq!(align_of::<Option<PadU64>>(), align_of::<u64>());
+        assert_eq!(align_of::<Option<PadUsize>>(), align_of::<usize>());
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(size_of::<PadU0>(), size_of::<()>());
+        assert_eq!(size_of::<PadFlexible>(), size_of::<()>());
+        assert_eq!(size_of::<PadU8>(), size_of::<u8>());
+        assert_eq!(size_of::<PadU16>(), size_of::<u16>());
+        assert_eq!(size_of::<PadU32>(), size_of::<u32>());
+        assert_eq!(size_of::<PadU64>(), size_of::<u64>());
+        assert_eq!(size_of::<PadBytes<0>>(), size_of::<[u8; 0]>());
+        assert_eq!(size_of::<PadBytes<3>>(), size_of::<[u8; 3]>());
+        assert_eq!(size_of::<PadUsize>(), size_of::<usize>());
+    }
+
+    #[test]
+    fn size_option() {
+        assert_eq!(size_of::<Option<PadU0>>(), size_of::<Option<()>>());
+        assert_eq!(size_of::<Option<PadFlexible>>(), size_of::<Option<()>>());
+        assert_eq!(size_of::<Option<PadU8>>(), size_of::<u8>());
+        assert_eq!(size_of::<Option<PadU16>>(), size_of::<u16>());
+        assert_eq!(size_of::<Option<PadU32>>(), size_of::<u32>());
+        assert_eq!(size_of::<Option<PadU64>>(), size_of::<u64>());
+        assert_eq!(size_of::<Option<PadBytes<3>>>(), size_of::<[u8; 3]>());
+        assert_eq!(size_of::<Option<PadUsize>>(), size_of::<usize>());
+    }
+
+    #[test]
+    fn has_niche() {
+        fn check<T: Pad>() {
+            assert_eq!(T::HAS_NICHE, size_of::<Option<T>>() == size_of::<T>());
+        }
+        check::<PadU0>();
+        check::<PadFlexible>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadBytes<0>>();
+        check::<PadBytes<3>>();
+        check::<PadSimd128>();
+        check::<PadSimd128Bytes>();
+        check::<PadWord>();
+        check::<PadWords<0>>();
+        check::<PadWords<3>>();
+        check::<PadUsize>();
+    }
+
+    #[test]
+    fn type_name() {
+        assert_eq!(PadU0::type_name(), "PadU0");
+        assert_eq!(PadFlexible::type_name(), "PadFlexible");
+        assert_eq!(PadU8::type_name(), "PadU8");
+        assert_eq!(PadU16::type_name(), "PadU16");
+        assert_eq!(PadU32::type_name(), "PadU32");
+        assert_eq!(PadU64::type_name(), "PadU64");
+        assert_eq!(PadBytes::<3>::type_name(), "PadBytes");
+        assert_eq!(PadMarker::<str>::type_name(), "PadMarker");
+        assert_eq!(PadSimd128::type_name(), "PadSimd128");
+        assert_eq!(PadSimd128Bytes::type_name(), "PadSimd128Bytes");
+        assert_eq!(PadWord::type_name(), "PadWord");
+        assert_eq!(PadWords::<3>::type_name(), "PadWords");
+    }
+
+    #[test]
+    fn write_pad() {
+        use core::mem::MaybeUninit;
+        use core::ptr::addr_of_mut;
+
+        #[repr(C)]
+        struct Message {
+            tag: u8,
+            pad1: PadU8,
+            pad2: PadU16,
+            len: u32,
+        }
+
+        let mut message = MaybeUninit::<Message>::uninit();
+        let ptr = message.as_mut_ptr();
+        // SAFETY: every field is written through a valid, aligned pointer
+        // before the struct is assumed to be initialized.
+        let message = unsafe {
+            addr_of_mut!((*ptr).tag).write(1);
+            super::write_pad(addr_of_mut!((*ptr).pad1));
+            super::write_pad(addr_of_mut!((*ptr).pad2));
+            addr_of_mut!((*ptr).len).write(2);
+            message.assume_init()
+        };
+        assert_eq!((message.tag, message.len), (1, 2));
+        assert_eq!(message.pad1, PadU8::VALUE);
+        assert_eq!(message.pad2, PadU16::VALUE);
+
+        let mut bytes = MaybeUninit::<PadBytes<5>>::uninit();
+        // SAFETY: `bytes` is valid for writes, and properly aligned.
+        let bytes = unsafe {
+            super::write_pad(bytes.as_mut_ptr());
+            bytes.assume_init()
+        };
+        assert_eq!(bytes, PadBytes::VALUE);
+    }
+
+    #[test]
+    fn gapless() {
+        fn check<T: Pad>() {
+            assert!(pad_is_gapless::<T>());
+            assert_eq!(size_of::<T>() % align_of::<T>(), 0);
+        }
+        check::<PadU0>();
+        check::<PadFlexible>();
+        check::<PadMarker<u64>>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadUsize>();
+        check::<PadBytes<0>>();
+        check::<PadBytes<7>>();
+        check::<PadSimd128>();
+        check::<PadSimd128Bytes>();
+        check::<PadWord>();
+        check::<PadWords<5>>();
+        check::<ZeroedPad<PadU16>>();
+    }
+
+    #[test]
+    fn option_niche() {
+        fn check<T: Pad>() {
+            assert_eq!(has_option_niche::<T>(), T::HAS_NICHE);
+        }
+        assert!(has_option_niche::<PadU32>());
+        assert!(!has_option_niche::<PadU0>());
+        assert_eq!(size_of::<Option<PadU0>>(), 1);
+        check::<PadU0>();
+        check::<PadFlexible>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadBytes<0>>();
+        check::<PadBytes<5>>();
+        check::<PadMarker<u64>>();
+        check::<PadSimd128>();
+        check::<PadSimd128Bytes>();
+        check::<PadWord>();
+        check::<PadWords<0>>();
+        check::<PadWords<3>>();
+    }
+
+    #[test]
+    fn fills_gap() {
+        assert!(pad_fills_gap::<PadU32>(4));
+        assert!(!pad_fills_gap::<PadU32>(3));
+        assert!(!pad_fills_gap::<PadU32>(5));
+        assert!(pad_fills_gap::<PadU0>(0));
+        assert!(pad_fills_gap::<PadBytes<7>>(7));
+        let before = StructLayout::new().field(1, 1);
+        let gap = before.field(8, 8).offset() - before.offset() - 8;
+        assert!(pad_fills_gap::<PadBytes<7>>(gap));
+    }
+
+    #[test]
+    fn nonzero_pad() {
+        assert!(!expect_nonzero_pad::<PadU0>());
+        assert!(!expect_nonzero_pad::<PadFlexible>());
+        assert!(!expect_nonzero_pad::<PadBytes<0>>());
+        assert!(expect_nonzero_pad::<PadU8>());
+        assert!(expect_nonzero_pad::<PadU64>());
+        assert!(expect_nonzero_pad::<PadBytes<3>>());
+    }
+
+    #[test]
+    fn zst_stacking() {
+        type Ten = (
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+            PadU0,
+        );
+        type Hundred = (Ten, Ten, Ten, Ten, Ten, Ten, Ten, Ten, Ten, Ten);
+
+        #[repr(C)]
+        struct Marked {
+            a: u32,
+            markers: Hundred,
+            b: u16,
+        }
+
+        assert_eq!(size_of::<Hundred>(), zst_cost(100));
+        assert_eq!(align_of::<Hundred>(), 1);
+        assert_eq!(size_of::<[PadU0; 100]>(), zst_cost(100));
+        assert_eq!(size_of::<Marked>(), size_of::<(u32, u16)>());
+        assert_eq!(align_of::<Marked>(), align_of::<u32>());
+        assert_eq!(zst_cost(0), 0);
+        assert_eq!(zst_cost(usize::MAX), 0);
+    }
+
+    #[test]
+    fn token() {
+        const TOKEN: PadU0 = PadU0::token();
+        const SPLIT: (PadU0, PadU0) = TOKEN.split_token();
+        assert_eq!(TOKEN, PadU0::VALUE);
+        assert_eq!(SPLIT, (PadU0::VALUE, PadU0::VALUE));
+        assert_eq!(size_of_val(&SPLIT), 0);
+        let (a, b) = SPLIT.0.split_token();
+        assert_eq!((a, b), SPLIT);
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(describe_pad::<PadU32>(), (4, 4));
+        assert_eq!(describe_pad::<PadU0>(), (0, 1));
+        assert_eq!(describe_pad::<PadBytes<3>>(), (3, 1));
+        assert_eq!(describe_pad::<PadU64>(), (8, align_of::<u64>()));
+        assert_eq!(
+            struct_size(&[describe_pad::<PadU8>(), describe_pad::<PadU16>()]),
+            4
+        );
+    }
+
+    #[test]
+    fn max_size_align() {
+        #[allow(dead_code)]
+        union Reserved {
+            a: PadU16,
+            b: PadU32,
+            c: PadBytes<3>,
+        }
+
+        assert_eq!(max_size::<PadU16, PadU32>(), 4);
+        assert_eq!(max_size::<PadU32, PadU16>(), 4);
+        assert_eq!(max_size::<PadU0, PadBytes<6>>(), 6);
+        assert_eq!(max_align::<PadU16, PadU32>(), align_of::<PadU32>());
+        assert_eq!(max_align::<PadBytes<6>, PadU0>(), 1);
+        assert_eq!(max_align::<PadSimd128, PadU8>(), 16);
+        assert_eq!(
+            max_size::<PadU16, PadU32>().max(PadBytes::<3>::SIZE),
+            size_of::<Reserved>()
+        );
+        assert_eq!(max_align::<PadU16, PadU32>(), align_of::<Reserved>());
+    }
+
+    #[test]
+    fn size_align() {
+        fn check<T: Pad>() {
+            assert_eq!(T::SIZE, size_of::<T>());
+            assert_eq!(T::ALIGN, align_of::<T>());
+        }
+        check::<PadU0>();
+        check::<PadFlexible>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadBytes<3>>();
+        check::<PadSimd128>();
+        check::<PadSimd128Bytes>();
+        check::<PadWord>();
+        check::<PadWords<0>>();
+        check::<PadWords<3>>();
+        check::<PadUsize>();
+    }
+
+    #[test]
+    fn bytes() {
+        fn check<T: Pad>() {
+            assert_eq!(T::VALUE.bytes().len(), T::SIZE);
+            assert!(all_zero(T::VALUE.bytes()));
+        }
+        check::<PadU0>();
+        check::<PadFlexible>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadBytes<0>>();
+        check::<PadBytes<3>>();
+        check::<PadBytes<100>>();
+        check::<PadSimd128>();
+        check::<PadSimd128Bytes>();
+        check::<PadWord>();
+        check::<PadWords<0>>();
+        check::<PadWords<3>>();
+        check::<PadUsize>();
+        assert_eq!(PadU32::VALUE.bytes().iter().filter(|&&b| b == 0).count(), 4);
+    }
+
+    #[test]
+    fn size_array_len() {
+        let bytes = [0u8; PadU32::SIZE];
+        assert_eq!(bytes.len(), 4);
+        let words: [u8; <PadWords<3> as Pad>::SIZE] = [0; 24];
+        assert_eq!(words.len(), 24);
+        const STATIC_LEN: usize = PadU16::SIZE + PadU8::SIZE;
+        static RESERVED: [u8; STATIC_LEN] = [0; STATIC_LEN];
+        assert_eq!(RESERVED.len(), 3);
+    }
+
+    #[test]
+    fn size_const_generic() {
+        fn zeros<const N: usize>() -> [u8; N] {
+            [0; N]
+        }
+        assert_eq!(zeros::<{ PadU32::SIZE }>(), [0; 4]);
+        assert_eq!(size_of::<PadBytes<{ PadU64::SIZE }>>(), 8);
+        assert_eq!(size_of::<PadBytes<{ PadSimd128Bytes::SIZE }>>(), 16);
+    }
+
+    #[test]
+    fn matches_primitive_size() {
+        assert!(PadU0::matches_primitive_size::<()>());
+        assert!(PadU8::matches_primitive_size::<i8>());
+        assert!(PadU16::matches_primitive_size::<u16>());
+        assert!(PadU32::matches_primitive_size::<f32>());
+        assert!(!PadU32::matches_primitive_size::<u64>());
+        assert!(PadU64::matches_primitive_size::<f64>());
+        assert!(PadBytes::<3>::matches_primitive_size::<[u8; 3]>());
+    }
+
+    #[test]
+    fn byte_offsets() {
+        assert_eq!(PadU32::VALUE.byte_offsets(8), 8..12);
+        assert!(PadU32::VALUE
+            .byte_offsets(8)
+            .eq([8, 9, 10, 11].iter().copied()));
+        assert_eq!(PadU64::VALUE.byte_offsets(0), 0..8);
+        assert!(PadU0::VALUE.byte_offsets(3).is_empty());
+        assert_eq!(PadBytes::<3>::VALUE.byte_offsets(5).len(), 3);
+    }
+
+    #[test]
+    fn align_contribution() {
+        #[repr(C)]
+        struct Example {
+            a: PadU8,
+            b: PadU32,
+            c: PadU16,
+        }
+
+        let example = Example {
+            a: Pad::VALUE,
+            b: Pad::VALUE,
+            c: Pad::VALUE,
+        };
+        let align = [
+            example.a.align_contribution(),
+            example.b.align_contribution(),
+            example.c.align_contribution(),
+        ]
+        .iter()
+        .fold(1, |a, &b| a.max(b));
+        assert_eq!(align, 4);
+        assert_eq!(align, align_of::<Example>());
+        assert_eq!(PadSimd128::VALUE.align_contribution(), 16);
+        assert_eq!(PadBytes::<64>::VALUE.align_contribution(), 1);
+    }
+
+    #[test]
+    fn from_seed() {
+        fn check<T: Pad + PartialEq + core::fmt::Debug>() {
+            for &seed in &[0, 1, 42, u64::MAX] {
+                assert_eq!(T::from_seed(seed), T::VALUE);
+            }
+        }
+        check::<PadU0>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadBytes<5>>();
+        check::<PadWords<2>>();
+        check::<PadMarker<u8>>();
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(PadU8::VALUE[0], 0);
+        assert_eq!(PadU16::VALUE[1], 0);
+        assert_eq!(PadU32::VALUE[3], 0);
+        assert_eq!(PadU64::VALUE[7], 0);
+        assert_eq!(PadBytes::<3>::VALUE[2], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _ = PadU32::VALUE[4];
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_empty() {
+        let _ = PadU0::VALUE[0];
+    }
+
+    #[test]
     fn bit_pattern() {
         assert_eq!(PadU8::VALUE.0 as u8, 0);
         assert_eq!(PadU16::VALUE.0 as u16, 0);
@@ -511,4 +2731,498 @@ mod tests {
         assert_eq!(PadU64::default().0 as u64, 0);
         assert_eq!(PadUsize::default().0 as usize, 0);
     }
+
+    // Zero has the same representation in either byte order, so these
+    // assertions hold regardless of `target_endian`.
+    #[test]
+    fn bit_pattern_bytes() {
+        // SAFETY: pads have no uninitialized bytes.
+        let bytes: [u8; 1] = unsafe { core::mem::transmute(PadU8::VALUE) };
+        assert_eq!(bytes, [0; 1]);
+        let bytes: [u8; 2] = unsafe { core::mem::transmute(PadU16::VALUE) };
+        assert_eq!(bytes, [0; 2]);
+        assert_eq!(u16::from_be_bytes(bytes), u16::from_le_bytes(bytes));
+        let bytes: [u8; 4] = unsafe { core::mem::transmute(PadU32::VALUE) };
+        assert_eq!(bytes, [0; 4]);
+        assert_eq!(u32::from_be_bytes(bytes), u32::from_le_bytes(bytes));
+        let bytes: [u8; 8] = unsafe { core::mem::transmute(PadU64::VALUE) };
+        assert_eq!(bytes, [0; 8]);
+        assert_eq!(u64::from_be_bytes(bytes), u64::from_le_bytes(bytes));
+        let bytes: [u8; 3] = unsafe { core::mem::transmute(PadBytes::<3>::VALUE) };
+        assert_eq!(bytes, [0; 3]);
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Padded {
+        a: u8,
+        pad1: PadU8,
+        b: u16,
+        pad2: PadU32,
+    }
+
+    #[test]
+    fn zeroed() {
+        // SAFETY: all-zeros is the only valid bit-pattern of every pad.
+        let pads: (PadU0, PadU8, PadU16, PadU32, PadU64, PadBytes<3>) =
+            unsafe { core::mem::zeroed() };
+        assert_eq!(pads, Default::default());
+    }
+
+    #[test]
+    fn bit_copy() {
+        let src = Padded {
+            a: 1,
+            pad1: Pad::VALUE,
+            b: 2,
+            pad2: Pad::VALUE,
+        };
+        let mut dst = core::mem::MaybeUninit::<Padded>::uninit();
+        // SAFETY: `src` and `dst` are valid, aligned and non-overlapping,
+        // so `dst` is fully initialized after the copy.
+        let dst = unsafe {
+            core::ptr::copy_nonoverlapping(&src, dst.as_mut_ptr(), 1);
+            dst.assume_init()
+        };
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn byte_round_trip() {
+        let src = Padded {
+            a: 1,
+            pad1: Pad::VALUE,
+            b: 2,
+            pad2: Pad::VALUE,
+        };
+        // SAFETY: `Padded` has no implicit padding, so every byte is initialized.
+        let bytes: [u8; 8] = unsafe { core::mem::transmute(src) };
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[1], 0);
+        assert_eq!(u16::from_ne_bytes([bytes[2], bytes[3]]), 2);
+        assert_eq!(bytes[4..], [0; 4]);
+        // SAFETY: the pad bytes are zero, as they were read from valid pads.
+        let dst: Padded = unsafe { core::mem::transmute(bytes) };
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn clone_from() {
+        let mut pad = PadU32::VALUE;
+        pad.clone_from(&PadU32::VALUE);
+        assert_eq!(pad, PadU32::VALUE);
+        let mut pad = PadBytes::<3>::VALUE;
+        pad.clone_from(&PadBytes::VALUE);
+        assert_eq!(pad, PadBytes::VALUE);
+    }
+
+    #[deny(improper_ctypes, improper_ctypes_definitions)]
+    mod ffi {
+        use super::*;
+
+        #[repr(C)]
+        pub struct Header {
+            pub tag: u8,
+            pub pad0: PadU0,
+            pub pad1: PadU8,
+            pub pad2: PadU16,
+            pub len: u32,
+            pub pad3: PadU32,
+            pub pad4: PadU64,
+            pub pad5: PadBytes<3>,
+            pub pad6: PadUsize,
+        }
+
+        pub extern "C" fn header_len(header: Header) -> u32 {
+            header.len
+        }
+
+        pub extern "C" fn echo_pads(pad0: PadU0, pad1: PadU32) -> PadU32 {
+            let _ = pad0;
+            pad1
+        }
+
+        #[allow(dead_code)]
+        extern "C" {
+            pub fn external(header: *const Header, pad0: PadU0, pad1: PadU32);
+        }
+    }
+
+    #[test]
+    fn ffi_safe() {
+        let header = ffi::Header {
+            tag: 1,
+            pad0: Pad::VALUE,
+            pad1: Pad::VALUE,
+            pad2: Pad::VALUE,
+            len: 2,
+            pad3: Pad::VALUE,
+            pad4: Pad::VALUE,
+            pad5: Pad::VALUE,
+            pad6: Pad::VALUE,
+        };
+        assert_eq!(header.tag, 1);
+        assert_eq!(ffi::header_len(header), 2);
+        assert_eq!(ffi::echo_pads(PadU0::VALUE, PadU32::VALUE), PadU32::VALUE);
+    }
+
+    #[test]
+    fn layout_eq() {
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Old {
+            a: u64,
+            b: u8,
+        }
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct New {
+            a: u64,
+            b: u8,
+            pad: PadBytes<{ core::mem::align_of::<u64>() - 1 }>,
+        }
+        assert_layout_eq!(Old, New);
+        assert_layout_eq!(PadUsize, usize);
+        assert_layout_eq!(PadBytes<4>, [u8; 4]);
+    }
+
+    #[test]
+    fn pad_matches() {
+        use core::ffi::{c_char, c_longlong, c_short, c_uint};
+
+        assert_pad_matches!(PadU8, c_char);
+        assert_pad_matches!(PadU16, c_short);
+        assert_pad_matches!(PadU64, c_longlong);
+        assert_pad_matches!(PadU32, c_uint);
+        assert_pad_matches!(PadBytes<3>, [c_char; 3]);
+    }
+
+    #[test]
+    fn reset() {
+        let mut pad = PadU32::VALUE;
+        pad.reset();
+        assert_eq!(pad, PadU32::VALUE);
+        let mut pads = (PadU0::VALUE, PadU64::VALUE, PadBytes::<3>::VALUE);
+        pads.0.reset();
+        pads.1.reset();
+        pads.2.reset();
+        assert_eq!(pads, Default::default());
+    }
+
+    #[test]
+    fn flexible() {
+        #[repr(C)]
+        struct Message {
+            len: u16,
+            kind: u8,
+            pad: PadU8,
+            data: PadFlexible,
+        }
+        assert_eq!(size_of::<Message>(), 4);
+        assert_eq!(core::mem::offset_of!(Message, data), 4);
+    }
+
+    #[test]
+    fn marker() {
+        fn covariant<'a>(marker: PadMarker<&'static str>) -> PadMarker<&'a str> {
+            marker
+        }
+
+        #[repr(C)]
+        struct Handle<T> {
+            id: u32,
+            marker: PadMarker<T>,
+        }
+
+        assert_eq!(size_of::<PadMarker<u64>>(), 0);
+        assert_eq!(align_of::<PadMarker<u64>>(), 1);
+        assert_eq!(size_of::<PadMarker<[u64]>>(), 0);
+        assert_eq!(size_of::<Handle<[u8; 64]>>(), 4);
+        let handle = Handle::<u64> {
+            id: 1,
+            marker: Pad::VALUE,
+        };
+        assert_eq!(handle.id, 1);
+        assert_eq!(handle.marker, PadMarker::default());
+        assert_eq!(covariant(PadMarker::VALUE), PadMarker::VALUE);
+        assert!(PadMarker::<u8>::VALUE.bytes().is_empty());
+    }
+
+    #[test]
+    fn bytes_align() {
+        assert_eq!(align_of::<PadBytes<0>>(), 1);
+        assert_eq!(align_of::<PadBytes<1>>(), 1);
+        assert_eq!(align_of::<PadBytes<2>>(), 1);
+        assert_eq!(align_of::<PadBytes<4>>(), 1);
+        assert_eq!(align_of::<PadBytes<7>>(), 1);
+        assert_eq!(align_of::<PadBytes<8>>(), 1);
+        assert_eq!(align_of::<PadBytes<64>>(), 1);
+        assert_eq!(align_of::<PadBytes<4096>>(), 1);
+
+        #[repr(C, packed)]
+        struct Packed {
+            a: u8,
+            pad: PadBytes<3>,
+            b: u32,
+        }
+        assert_eq!(size_of::<Packed>(), 8);
+        assert_eq!(core::mem::offset_of!(Packed, pad), 1);
+    }
+
+    #[test]
+    fn layout_report() {
+        #[repr(C)]
+        struct Example {
+            a: u8,
+            pad1: PadU8,
+            pad2: PadU16,
+            b: u32,
+            c: u16,
+            pad3: PadBytes<6>,
+        }
+        const REPORT: [(usize, usize); 6] = layout_report!(Example {
+            a,
+            pad1,
+            pad2,
+            b,
+            c,
+            pad3,
+        });
+        assert_eq!(REPORT, [(0, 1), (1, 1), (2, 2), (4, 4), (8, 2), (10, 6)]);
+
+        struct Tuple(u16, PadU16, u32);
+        let report = layout_report!(Tuple { 2, 1, 0 });
+        assert_eq!(report.map(|(_, size)| size), [4, 2, 2]);
+    }
+
+    #[test]
+    fn simd() {
+        assert_eq!(align_of::<PadSimd128>(), 16);
+        assert_eq!(size_of::<PadSimd128>(), 0);
+        assert_eq!(align_of::<PadSimd128Bytes>(), 16);
+        assert_eq!(size_of::<PadSimd128Bytes>(), 16);
+        assert_eq!(size_of::<Option<PadSimd128Bytes>>(), 16);
+
+        #[repr(C)]
+        struct Scratch {
+            align: PadSimd128,
+            data: [f32; 3],
+        }
+        assert_eq!(align_of::<Scratch>(), 16);
+        assert_eq!(size_of::<Scratch>(), 16);
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(size_of::<PadWord>(), 8);
+        assert_eq!(align_of::<PadWord>(), 8);
+        assert_eq!(size_of::<PadWords<3>>(), 24);
+        assert_eq!(align_of::<PadWords<3>>(), 8);
+        assert_eq!(size_of::<PadWords<0>>(), 0);
+        assert_eq!(align_of::<PadWords<0>>(), 8);
+        assert_eq!(PadWords::<3>::VALUE.bytes(), [0; 24]);
+        assert_eq!(PadWords::<3>::VALUE[23], 0);
+
+        #[repr(C)]
+        struct Segment {
+            tag: u8,
+            words: PadWords<2>,
+        }
+        assert_eq!(size_of::<Segment>(), 24);
+    }
+
+    #[test]
+    fn reserved() {
+        #[repr(C)]
+        struct Registers {
+            control: u32,
+            _reserved0: reserved!(0x10, "firmware revision"),
+            status: u32,
+            _reserved1: reserved!(4),
+        }
+        let regs = Registers {
+            control: 0,
+            _reserved0: Pad::VALUE,
+            status: 0,
+            _reserved1: Pad::VALUE,
+        };
+        assert_eq!(regs.control, regs.status);
+        assert_eq!(size_of::<Registers>(), 28);
+    }
+
+    #[test]
+    fn all_pads() {
+        const FIXTURE: (PadU32, PadU8, PadU8, PadU16) = all_pads!(PadU32, PadU8, PadU8, PadU16);
+        const SINGLE: (PadU64,) = all_pads!(PadU64);
+        assert_eq!(size_of_val(&FIXTURE), 4 + 1 + 1 + 2);
+        assert_eq!(size_of_val(&SINGLE), PadU64::SIZE);
+        assert_eq!(all_pads!(), ());
+    }
+
+    #[test]
+    fn atomic_neighbour() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        #[repr(C)]
+        struct Slot {
+            pad: PadU32,
+            state: AtomicU32,
+        }
+
+        static SLOT: Slot = Slot {
+            pad: PadU32::VALUE,
+            state: AtomicU32::new(0),
+        };
+        // SAFETY: `Slot` is `repr(C)`, so its fields are laid out in order.
+        let offset = unsafe {
+            (&SLOT.state as *const AtomicU32 as *const u8)
+                .offset_from(&SLOT as *const Slot as *const u8)
+        };
+        assert_eq!(size_of::<Slot>(), 8);
+        assert_eq!(align_of::<Slot>(), align_of::<AtomicU32>());
+        assert_eq!(offset, 4);
+        SLOT.state.store(7, Ordering::SeqCst);
+        assert_eq!(SLOT.state.load(Ordering::SeqCst), 7);
+        assert_eq!(SLOT.pad, PadU32::VALUE);
+    }
+
+    #[test]
+    fn assert_clean() {
+        PadU0::VALUE.assert_clean(&[]);
+        PadU16::VALUE.assert_clean(&[0; 2]);
+        PadBytes::<12>::VALUE.assert_clean(&[0; 12]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "padding must be zero")]
+    fn assert_clean_nonzero() {
+        PadU32::VALUE.assert_clean(&[0, 0, 1, 0]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "padding has the wrong size")]
+    fn assert_clean_wrong_size() {
+        PadU32::VALUE.assert_clean(&[0; 2]);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn assert_clean_release() {
+        PadU32::VALUE.assert_clean(&[0, 0, 1, 0]);
+        PadU32::VALUE.assert_clean(&[0; 2]);
+    }
+
+    #[test]
+    fn c_reserved() {
+        const SNIPPET: &str = c_reserved!(16);
+        assert_eq!(SNIPPET, "uint8_t reserved[16];");
+        assert_eq!(c_reserved!(4, reserved1), "uint8_t reserved1[4];");
+        assert_eq!(c_reserved!(0x10), "uint8_t reserved[16];");
+    }
+
+    #[test]
+    fn reserved_field() {
+        #[repr(C)]
+        struct V1 {
+            tag: u8,
+            _flags: reserved_field!(u8),
+            _len: reserved_field!(u16),
+            value: reserved_field!(f32),
+        }
+
+        #[repr(C)]
+        struct V2 {
+            tag: u8,
+            flags: u8,
+            len: u16,
+            value: f32,
+        }
+
+        assert_layout_eq!(V1, V2);
+        let v1 = V1 {
+            tag: 0,
+            _flags: Pad::VALUE,
+            _len: Pad::VALUE,
+            value: PadU32::VALUE,
+        };
+        let v2 = V2 {
+            tag: 0,
+            flags: 0,
+            len: 0,
+            value: 0.0,
+        };
+        assert_eq!(v1.tag, v2.tag + v2.flags);
+        assert_eq!(v1.value, PadU32::VALUE);
+        assert_eq!(v2.len, 0);
+        assert_eq!(v2.value, 0.0);
+        assert_eq!(size_of::<V1>(), 8);
+    }
+
+    #[test]
+    fn pad_slice() {
+        let mut buf = [0xff; 8];
+        buf[..3].copy_from_slice(b"abc");
+        pad_slice_to(&mut buf, 3, 6);
+        assert_eq!(buf, [b'a', b'b', b'c', 0, 0, 0, 0xff, 0xff]);
+        pad_slice_to(&mut buf, 6, 8);
+        assert!(all_zero(&buf[3..]));
+        pad_slice_to(&mut buf, 2, 2);
+        assert_eq!(buf[..3], *b"abc");
+        pad_slice_to(&mut [], 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the buffer length")]
+    fn pad_slice_long_target() {
+        pad_slice_to(&mut [0; 4], 2, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pad_slice_used_past_target() {
+        pad_slice_to(&mut [0; 4], 3, 2);
+    }
+
+    #[test]
+    fn builtin_pad_size() {
+        assert!(is_builtin_pad_size(0));
+        assert!(is_builtin_pad_size(4));
+        assert!(is_builtin_pad_size(MAX_BUILTIN_PAD_SIZE));
+        assert!(!is_builtin_pad_size(3));
+        assert!(!is_builtin_pad_size(5));
+        assert!(!is_builtin_pad_size(16));
+    }
+
+    #[test]
+    fn zero_bytes() {
+        assert!(all_zero(&[]));
+        assert!(all_zero(&[0, 0, 0]));
+        assert!(!all_zero(&[0, 1]));
+        assert!(!all_zero(&[1, 0]));
+    }
+
+    #[test]
+    fn size_for_align() {
+        assert_eq!(pad_size_for_align(1), 1);
+        assert_eq!(pad_size_for_align(2), 2);
+        assert_eq!(pad_size_for_align(4), 4);
+        assert_eq!(pad_size_for_align(align_of::<u64>()), align_of::<u64>());
+        let _: pad_for_align!(1) = PadU8::VALUE;
+        let _: pad_for_align!(2) = PadU16::VALUE;
+        let _: pad_for_align!(4) = PadU32::VALUE;
+        assert_eq!(align_of::<pad_for_align!(4)>(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn size_for_align_unsupported() {
+        let _ = pad_size_for_align(16);
+    }
+
+    #[test]
+    fn max_builtin_pad_size() {
+        assert_eq!(MAX_BUILTIN_PAD_SIZE, size_of::<PadU64>());
+    }
 }
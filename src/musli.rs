@@ -0,0 +1,114 @@
+use ::musli::de::SequenceDecoder;
+use ::musli::en::SequenceEncoder;
+use ::musli::{Allocator, Context, Decode, Decoder, Encode, Encoder};
+
+use crate::{
+    DontCarePad, Pad, PadBytes, PadFlexible, PadMarker, PadSimd128, PadSimd128Bytes, PadU0, PadU16,
+    PadU32, PadU64, PadU8, PadWord, PadWords, ZeroedPad,
+};
+
+// Pads are encoded as a pack of `P::SIZE` zero bytes, mirroring the
+// `serde` integration.
+fn encode<P: Pad, E: Encoder>(encoder: E) -> Result<(), E::Error> {
+    encoder.encode_pack_fn(|pack| {
+        for _ in 0..P::SIZE {
+            pack.encode_next()?.encode_u8(0)?;
+        }
+        Ok(())
+    })
+}
+
+fn decode<'de, P: Pad, D: Decoder<'de>>(decoder: D) -> Result<P, D::Error> {
+    let cx = decoder.cx();
+    decoder.decode_pack(|pack| {
+        for _ in 0..P::SIZE {
+            if pack.decode_next()?.decode_u8()? != 0 {
+                return Err(cx.message("padding must be zero"));
+            }
+        }
+        Ok(P::VALUE)
+    })
+}
+
+macro_rules! impl_musli {
+    ($(impl[$($generics:tt)*] $ty:ty),*) => {
+        $(
+            impl<M, $($generics)*> Encode<M> for $ty {
+                type Encode = Self;
+
+                #[inline]
+                fn encode<E: Encoder<Mode = M>>(&self, encoder: E) -> Result<(), E::Error> {
+                    encode::<Self, E>(encoder)
+                }
+
+                #[inline]
+                fn as_encode(&self) -> &Self::Encode {
+                    self
+                }
+            }
+
+            impl<'de, M, A: Allocator, $($generics)*> Decode<'de, M, A> for $ty {
+                #[inline]
+                fn decode<D>(decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M, Allocator = A>,
+                {
+                    decode(decoder)
+                }
+            }
+        )*
+    };
+}
+
+impl_musli!(
+    impl[] PadU0,
+    impl[] PadFlexible,
+    impl[] PadU8,
+    impl[] PadU16,
+    impl[] PadU32,
+    impl[] PadU64,
+    impl[] PadSimd128,
+    impl[] PadSimd128Bytes,
+    impl[] PadWord,
+    impl[const N: usize] PadBytes<N>,
+    impl[const N: usize] PadWords<N>,
+    impl[T: ?Sized] PadMarker<T>,
+    impl[P: Pad] ZeroedPad<P>,
+    impl[P: Pad] DontCarePad<P>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, ::musli::Decode, ::musli::Encode)]
+    struct Message {
+        tag: u8,
+        pad: PadU32,
+        reserved: PadBytes<3>,
+        len: u16,
+    }
+
+    #[test]
+    fn storage_round_trip() {
+        let message = Message {
+            tag: 1,
+            pad: Pad::VALUE,
+            reserved: Pad::VALUE,
+            len: 2,
+        };
+        let bytes = ::musli::storage::to_vec(&message).unwrap();
+        let decoded: Message = ::musli::storage::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn storage_nonzero() {
+        let mut bytes = ::musli::storage::to_vec(&PadU32::VALUE).unwrap();
+        assert_eq!(bytes, [0; 4]);
+        assert!(::musli::storage::from_slice::<PadU32>(&bytes).is_ok());
+        bytes[2] = 1;
+        assert!(::musli::storage::from_slice::<PadU32>(&bytes).is_err());
+        assert!(::musli::storage::from_slice::<PadU32>(&bytes[..3]).is_err());
+    }
+}
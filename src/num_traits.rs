@@ -0,0 +1,88 @@
+// Padding types have a single value, which doubles as their zero. Adding
+// two pads yields that same value, which `Zero` requires of its
+// implementors.
+
+use core::ops::Add;
+
+use ::num_traits::Zero;
+
+use crate::{
+    DontCarePad, Pad, PadBytes, PadFlexible, PadMarker, PadSimd128, PadSimd128Bytes, PadU0, PadU16,
+    PadU32, PadU64, PadU8, PadWord, PadWords, ZeroedPad,
+};
+
+macro_rules! impl_zero {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Add for $ty {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, _: Self) -> Self {
+                    Self::VALUE
+                }
+            }
+
+            impl<$($generics)*> Zero for $ty {
+                #[inline]
+                fn zero() -> Self {
+                    Self::VALUE
+                }
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_zero!(
+    [] PadU0,
+    [] PadFlexible,
+    [] PadU8,
+    [] PadU16,
+    [] PadU32,
+    [] PadU64,
+    [] PadSimd128,
+    [] PadSimd128Bytes,
+    [] PadWord,
+    [const N: usize] PadBytes<N>,
+    [const N: usize] PadWords<N>,
+    [T: ?Sized] PadMarker<T>,
+    [P: Pad] ZeroedPad<P>,
+    [P: Pad] DontCarePad<P>,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zeroed<T: Zero>() -> T {
+        let mut value = T::zero();
+        value.set_zero();
+        value
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(::num_traits::zero::<PadU32>(), PadU32::VALUE);
+        assert_eq!(<PadU32 as Zero>::zero(), PadU32::VALUE);
+        assert!(PadU64::zero().is_zero());
+        assert!(PadU8::VALUE.is_zero());
+        assert_eq!(zeroed::<PadBytes<5>>(), PadBytes::VALUE);
+        assert_eq!(zeroed::<PadWords<2>>(), PadWords::VALUE);
+        assert_eq!(PadU16::VALUE + PadU16::VALUE, PadU16::zero());
+    }
+
+    #[test]
+    fn zero_special() {
+        assert_eq!(zeroed::<PadFlexible>(), PadFlexible::VALUE);
+        assert_eq!(zeroed::<PadSimd128>(), PadSimd128::VALUE);
+        assert_eq!(zeroed::<PadSimd128Bytes>(), PadSimd128Bytes::VALUE);
+        assert_eq!(zeroed::<PadMarker<str>>(), PadMarker::VALUE);
+        assert_eq!(zeroed::<ZeroedPad<PadU32>>(), ZeroedPad::VALUE);
+        assert!(DontCarePad::<PadU64>::zero().is_zero());
+    }
+}
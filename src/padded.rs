@@ -0,0 +1,87 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Pad, PadBytes};
+
+/// A value followed by `N` bytes of trailing padding.
+///
+/// `Padded` is `#[repr(C)]`, with the wrapped value at offset zero followed
+/// by a [`PadBytes<N>`]. Its size is `size_of::<T>() + N`, rounded up to a
+/// multiple of the alignment of `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::size_of;
+/// use struct_pad::Padded;
+///
+/// let value = Padded::<u16, 6>::new(7);
+/// assert_eq!(*value, 7);
+/// assert_eq!(size_of::<Padded<u16, 6>>(), 8);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Padded<T, const N: usize> {
+    value: T,
+    pad: PadBytes<N>,
+}
+
+impl<T, const N: usize> Padded<T, N> {
+    /// Wraps `value`, followed by `N` bytes of padding.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            pad: PadBytes::VALUE,
+        }
+    }
+
+    /// Unwraps the inner value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, const N: usize> Deref for Padded<T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, const N: usize> DerefMut for Padded<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn size() {
+        assert_eq!(size_of::<Padded<u16, 6>>(), 8);
+        assert_eq!(size_of::<Padded<u16, 5>>(), 8);
+        assert_eq!(size_of::<Padded<u8, 0>>(), 1);
+        assert_eq!(size_of::<Padded<[u8; 3], 5>>(), 8);
+    }
+
+    #[test]
+    fn align() {
+        assert_eq!(align_of::<Padded<u16, 6>>(), align_of::<u16>());
+        assert_eq!(align_of::<Padded<u8, 7>>(), 1);
+    }
+
+    #[test]
+    fn deref() {
+        let mut value = Padded::<u16, 6>::new(7);
+        assert_eq!(*value, 7);
+        *value += 1;
+        assert_eq!(value.into_inner(), 8);
+    }
+}
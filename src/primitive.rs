@@ -0,0 +1,185 @@
+use crate::{Pad, PadU0, PadU16, PadU32, PadU64, PadU8, PadUsize};
+
+/// A primitive type with a corresponding padding type.
+///
+/// The associated padding type has the same size and alignment as `Self`,
+/// which allows generic code to name "the padding matching `T`". The one
+/// exception is the floats: `f32` and `f64` share the padding type of the
+/// integer of the same size, so on targets where a float is aligned
+/// differently from that integer, only the sizes are guaranteed to match.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{HasPad, PadU32};
+///
+/// let pad: <u32 as HasPad>::Pad = PadU32::default();
+/// ```
+pub trait HasPad {
+    /// The padding type with the same layout as `Self`.
+    type Pad: Pad;
+}
+
+/// The padding type with the same layout as `T`.
+///
+/// For integers, the alignment follows `align_of::<T>()` on the current
+/// target rather than a hardcoded value, so `PadMatching<u64>` is only
+/// 4-byte aligned wherever `u64` is (e.g. x86-32 Linux). For floats, see
+/// the exception noted on [`HasPad`].
+pub type PadMatching<T> = <T as HasPad>::Pad;
+
+impl HasPad for () {
+    type Pad = PadU0;
+}
+
+impl HasPad for u8 {
+    type Pad = PadU8;
+}
+
+impl HasPad for u16 {
+    type Pad = PadU16;
+}
+
+impl HasPad for u32 {
+    type Pad = PadU32;
+}
+
+impl HasPad for u64 {
+    type Pad = PadU64;
+}
+
+impl HasPad for usize {
+    type Pad = PadUsize;
+}
+
+impl HasPad for i8 {
+    type Pad = PadU8;
+}
+
+impl HasPad for i16 {
+    type Pad = PadU16;
+}
+
+impl HasPad for i32 {
+    type Pad = PadU32;
+}
+
+impl HasPad for i64 {
+    type Pad = PadU64;
+}
+
+impl HasPad for isize {
+    type Pad = PadUsize;
+}
+
+impl HasPad for f32 {
+    type Pad = PadU32;
+}
+
+impl HasPad for f64 {
+    type Pad = PadU64;
+}
+
+// Pin the natural alignment of each padding type to its integer primitive,
+// on every target. Floats may be aligned differently from the integers of
+// the same size, so they are only checked by the tests.
+const _: () = {
+    use core::mem::{align_of, size_of};
+    assert!(align_of::<PadMatching<u16>>() == align_of::<u16>());
+    assert!(align_of::<PadMatching<u32>>() == align_of::<u32>());
+    assert!(align_of::<PadMatching<u64>>() == align_of::<u64>());
+    assert!(size_of::<PadMatching<u64>>() == size_of::<u64>());
+};
+
+/// A padding type with a corresponding primitive type.
+///
+/// This is the reverse of [`HasPad`]: the associated primitive type has
+/// the same size and alignment as `Self`. The mapping is purely type-level,
+/// and has no runtime cost.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{PadToPrimitive, PadU32};
+///
+/// let value: <PadU32 as PadToPrimitive>::Primitive = 7u32;
+/// ```
+pub trait PadToPrimitive: Pad {
+    /// The primitive type with the same layout as `Self`.
+    type Primitive;
+}
+
+impl PadToPrimitive for PadU0 {
+    type Primitive = ();
+}
+
+impl PadToPrimitive for PadU8 {
+    type Primitive = u8;
+}
+
+impl PadToPrimitive for PadU16 {
+    type Primitive = u16;
+}
+
+impl PadToPrimitive for PadU32 {
+    type Primitive = u32;
+}
+
+impl PadToPrimitive for PadU64 {
+    type Primitive = u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    fn check<T: HasPad>() {
+        assert_eq!(size_of::<PadMatching<T>>(), size_of::<T>());
+        assert_eq!(align_of::<PadMatching<T>>(), align_of::<T>());
+    }
+
+    #[test]
+    fn has_pad() {
+        check::<()>();
+        check::<u8>();
+        check::<u16>();
+        check::<u32>();
+        check::<u64>();
+        check::<usize>();
+        check::<i8>();
+        check::<i16>();
+        check::<i32>();
+        check::<i64>();
+        check::<isize>();
+        check::<f32>();
+        check::<f64>();
+        let _: <u32 as HasPad>::Pad = PadU32::VALUE;
+        let _: PadMatching<f64> = PadU64::VALUE;
+        let unit: <() as HasPad>::Pad = PadU0::VALUE;
+        assert_eq!(core::mem::size_of_val(&unit), 0);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn natural_align_32() {
+        assert_eq!(align_of::<PadU64>(), align_of::<u64>());
+        assert_eq!(align_of::<PadMatching<i64>>(), align_of::<i64>());
+        assert_eq!(align_of::<PadMatching<f64>>(), align_of::<f64>());
+    }
+
+    #[test]
+    fn to_primitive() {
+        fn check<P: PadToPrimitive>() {
+            assert_eq!(size_of::<P::Primitive>(), size_of::<P>());
+            assert_eq!(align_of::<P::Primitive>(), align_of::<P>());
+        }
+        check::<PadU0>();
+        check::<PadU8>();
+        check::<PadU16>();
+        check::<PadU32>();
+        check::<PadU64>();
+        check::<PadUsize>();
+        let _: <PadU32 as PadToPrimitive>::Primitive = 0u32;
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Pad, PadBytes};
+
+/// A reserved region of up to `CAP` bytes, of which some are in use.
+///
+/// `PadRegion` is `#[repr(C)]`, storing the used length as a `usize`
+/// followed by `CAP` bytes of padding. It models variable amounts of
+/// reserved space within a fixed-size struct: however much of the
+/// region is in use, every byte of it remains zero.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::PadRegion;
+///
+/// let region = PadRegion::<16>::new(6);
+/// assert_eq!(region.used(), 6);
+/// assert_eq!(region.remaining(), 10);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct PadRegion<const CAP: usize> {
+    used: usize,
+    reserved: PadBytes<CAP>,
+}
+
+impl<const CAP: usize> PadRegion<CAP> {
+    /// Creates a region with `used` bytes in use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `used` is greater than `CAP`.
+    #[inline]
+    #[must_use]
+    pub const fn new(used: usize) -> Self {
+        assert!(
+            used <= CAP,
+            "used length exceeds the capacity of the region"
+        );
+        Self {
+            used,
+            reserved: PadBytes::VALUE,
+        }
+    }
+
+    /// Returns the total number of bytes in the region.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the number of bytes in use.
+    #[inline]
+    #[must_use]
+    pub const fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Returns the number of bytes not in use.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        CAP - self.used
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::size_of;
+
+    #[test]
+    fn new() {
+        let region = PadRegion::<16>::new(6);
+        assert_eq!(region.capacity(), 16);
+        assert_eq!(region.used(), 6);
+        assert_eq!(region.remaining(), 10);
+        assert_eq!(PadRegion::<16>::default().remaining(), 16);
+        assert_eq!(PadRegion::<16>::new(16).remaining(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_over_capacity() {
+        let _ = PadRegion::<4>::new(5);
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(size_of::<PadRegion<0>>(), size_of::<usize>());
+        assert_eq!(
+            size_of::<PadRegion<16>>(),
+            size_of::<usize>() + size_of::<[u8; 16]>()
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{all_zero, Pad, PadWord};
+
+/// An 8-byte, 8-aligned scratch word with padding semantics.
+///
+/// `ScratchWord` has the same layout as [`PadWord`], and starts out all
+/// zeros. Unlike a padding type, its bytes may be overwritten, e.g. by a
+/// memory pool reusing reserved space. Once written, it no longer holds
+/// the value of a [`PadWord`] until it is [`reset`](ScratchWord::reset).
+///
+/// Writing requires `&mut self`: `ScratchWord` has no interior mutability,
+/// so it may still be shared between threads like the padding types.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::ScratchWord;
+///
+/// let mut scratch = ScratchWord::new();
+/// scratch.as_mut_bytes().copy_from_slice(&42u64.to_ne_bytes());
+/// assert!(!scratch.is_reset());
+/// scratch.reset();
+/// assert!(scratch.is_reset());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C, align(8))]
+pub struct ScratchWord([u8; 8]);
+
+impl ScratchWord {
+    /// Creates a zeroed scratch word.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self([0; 8])
+    }
+
+    /// Returns the bytes of the scratch word.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// Returns the bytes of the scratch word, for writing.
+    #[inline]
+    pub fn as_mut_bytes(&mut self) -> &mut [u8; 8] {
+        &mut self.0
+    }
+
+    /// Zeroes the scratch word, restoring its padding value.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.0 = [0; 8];
+    }
+
+    /// Returns `true` if every byte of the scratch word is zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_reset(&self) -> bool {
+        all_zero(&self.0)
+    }
+
+    /// Returns the scratch word as a [`PadWord`], if it has been reset.
+    #[inline]
+    #[must_use]
+    pub const fn to_pad(&self) -> Option<PadWord> {
+        if self.is_reset() {
+            Some(PadWord::VALUE)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<ScratchWord>(), size_of::<PadWord>());
+        assert_eq!(align_of::<ScratchWord>(), align_of::<PadWord>());
+        assert_eq!(align_of::<ScratchWord>(), 8);
+    }
+
+    #[test]
+    fn write_reset() {
+        let mut scratch = ScratchWord::new();
+        assert!(scratch.is_reset());
+        assert_eq!(scratch.to_pad(), Some(PadWord::VALUE));
+        scratch
+            .as_mut_bytes()
+            .copy_from_slice(&0xdead_beef_cafe_f00d_u64.to_ne_bytes());
+        assert_eq!(
+            u64::from_ne_bytes(*scratch.as_bytes()),
+            0xdead_beef_cafe_f00d
+        );
+        assert!(!scratch.is_reset());
+        assert_eq!(scratch.to_pad(), None);
+        scratch.reset();
+        assert_eq!(scratch.as_bytes(), &[0; 8]);
+        assert_eq!(scratch, ScratchWord::default());
+        assert_eq!(scratch.to_pad(), Some(PadWord::VALUE));
+    }
+}
@@ -0,0 +1,204 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::{
+    DontCarePad, Pad, PadBytes, PadFlexible, PadMarker, PadSimd128, PadSimd128Bytes, PadU0, PadU16,
+    PadU32, PadU64, PadU8, PadWord, PadWords, ZeroedPad,
+};
+
+// Pads are serialized as a tuple of `P::SIZE` zero bytes, so that formats
+// without a length prefix reproduce the in-memory layout.
+fn serialize<P: Pad, S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(P::SIZE)?;
+    for _ in 0..P::SIZE {
+        tuple.serialize_element(&0u8)?;
+    }
+    tuple.end()
+}
+
+fn deserialize<'de, P: Pad, D: Deserializer<'de>>(deserializer: D) -> Result<P, D::Error> {
+    deserializer.deserialize_tuple(P::SIZE, PadVisitor(PhantomData))
+}
+
+struct PadVisitor<P>(PhantomData<P>);
+
+impl<'de, P: Pad> Visitor<'de> for PadVisitor<P> {
+    type Value = P;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} zero bytes", P::SIZE)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<P, A::Error> {
+        for i in 0..P::SIZE {
+            match seq.next_element::<u8>()? {
+                Some(0) => {}
+                Some(byte) => {
+                    return Err(de::Error::invalid_value(
+                        Unexpected::Unsigned(byte.into()),
+                        &"a zero byte",
+                    ))
+                }
+                None => return Err(de::Error::invalid_length(i, &self)),
+            }
+        }
+        Ok(P::VALUE)
+    }
+}
+
+macro_rules! impl_serde {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                #[inline]
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize::<Self, S>(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(
+    PadU0,
+    PadFlexible,
+    PadU8,
+    PadU16,
+    PadU32,
+    PadU64,
+    PadSimd128,
+    PadSimd128Bytes,
+    PadWord
+);
+
+impl<const N: usize> Serialize for PadBytes<N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize::<Self, S>(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for PadBytes<N> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+macro_rules! impl_serde_wrapper {
+    ($($ty:ident),*) => {
+        $(
+            impl<P: Pad> Serialize for $ty<P> {
+                #[inline]
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize::<Self, S>(serializer)
+                }
+            }
+
+            impl<'de, P: Pad> Deserialize<'de> for $ty<P> {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_wrapper!(ZeroedPad, DontCarePad);
+
+impl<const N: usize> Serialize for PadWords<N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize::<Self, S>(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for PadWords<N> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<T: ?Sized> Serialize for PadMarker<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize::<Self, S>(serializer)
+    }
+}
+
+impl<'de, T: ?Sized> Deserialize<'de> for PadMarker<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+    struct Message {
+        tag: u8,
+        pad0: PadU0,
+        pad1: PadU8,
+        pad2: PadU16,
+        len: u32,
+        pad3: PadU32,
+        pad4: PadU64,
+        pad5: PadBytes<3>,
+    }
+
+    #[test]
+    fn postcard_round_trip() {
+        let message = Message {
+            tag: 1,
+            pad0: Pad::VALUE,
+            pad1: Pad::VALUE,
+            pad2: Pad::VALUE,
+            len: 2,
+            pad3: Pad::VALUE,
+            pad4: Pad::VALUE,
+            pad5: Pad::VALUE,
+        };
+        let bytes = postcard::to_allocvec(&message).unwrap();
+        assert_eq!(bytes.len(), 1 + 1 + 2 + 1 + 4 + 8 + 3);
+        assert_eq!(bytes[1..4], [0; 3]);
+        assert_eq!(bytes[5..], [0; 15]);
+        assert_eq!(postcard::from_bytes::<Message>(&bytes).unwrap(), message);
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        use bincode::config;
+        use bincode::serde::{decode_from_slice, encode_to_vec};
+
+        let bytes = encode_to_vec(PadU32::VALUE, config::legacy()).unwrap();
+        assert_eq!(bytes, [0; 4]);
+        let (pad, len) = decode_from_slice::<PadU32, _>(&bytes, config::legacy()).unwrap();
+        assert_eq!((pad, len), (PadU32::VALUE, 4));
+
+        let bytes = encode_to_vec((1u8, PadBytes::<3>::VALUE), config::standard()).unwrap();
+        assert_eq!(bytes, [1, 0, 0, 0]);
+        assert!(decode_from_slice::<PadU16, _>(&[0, 1], config::legacy()).is_err());
+    }
+
+    #[test]
+    fn postcard_nonzero() {
+        assert!(postcard::from_bytes::<PadU16>(&[0, 0]).is_ok());
+        assert!(postcard::from_bytes::<PadU16>(&[0, 1]).is_err());
+        assert!(postcard::from_bytes::<PadU16>(&[0]).is_err());
+    }
+}
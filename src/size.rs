@@ -0,0 +1,91 @@
+use crate::{
+    DontCarePad, Pad, PadBytes, PadFlexible, PadMarker, PadSimd128, PadSimd128Bytes, PadU0, PadU16,
+    PadU32, PadU64, PadU8, PadWord, ZeroedPad,
+};
+
+/// A padding type of exactly `N` bytes.
+///
+/// This allows generic APIs to demand a padding type of a given size, as
+/// in `P: SizeIs<4>`. On stable Rust, the trait cannot be implemented for
+/// every padding type with a [`SIZE`](Pad::SIZE) of `N` at once, which
+/// would require the unstable `generic_const_exprs` feature, so it is
+/// implemented for each padding type explicitly. It is not implemented for
+/// [`PadWords`](crate::PadWords), whose size is a multiple of its length.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{Pad, PadU32, SizeIs};
+///
+/// fn reserve<P: SizeIs<4>>() -> P {
+///     P::VALUE
+/// }
+///
+/// let pad: PadU32 = reserve();
+/// ```
+///
+/// Padding types of other sizes are rejected:
+///
+/// ```compile_fail
+/// use struct_pad::{Pad, PadU16, SizeIs};
+///
+/// fn reserve<P: SizeIs<4>>() -> P {
+///     P::VALUE
+/// }
+///
+/// let pad: PadU16 = reserve();
+/// ```
+pub trait SizeIs<const N: usize>: Pad {}
+
+impl SizeIs<0> for PadU0 {}
+
+impl SizeIs<0> for PadFlexible {}
+
+impl<T: ?Sized> SizeIs<0> for PadMarker<T> {}
+
+impl SizeIs<0> for PadSimd128 {}
+
+impl SizeIs<1> for PadU8 {}
+
+impl SizeIs<2> for PadU16 {}
+
+impl SizeIs<4> for PadU32 {}
+
+impl SizeIs<8> for PadU64 {}
+
+impl SizeIs<8> for PadWord {}
+
+impl SizeIs<16> for PadSimd128Bytes {}
+
+impl<const N: usize> SizeIs<N> for PadBytes<N> {}
+
+impl<P: SizeIs<N>, const N: usize> SizeIs<N> for ZeroedPad<P> {}
+
+impl<P: SizeIs<N>, const N: usize> SizeIs<N> for DontCarePad<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size<P: SizeIs<N>, const N: usize>() -> usize {
+        assert_eq!(P::SIZE, N);
+        N
+    }
+
+    #[test]
+    fn size_is() {
+        assert_eq!(size::<PadU0, 0>(), 0);
+        assert_eq!(size::<PadFlexible, 0>(), 0);
+        assert_eq!(size::<PadMarker<str>, 0>(), 0);
+        assert_eq!(size::<PadSimd128, 0>(), 0);
+        assert_eq!(size::<PadU8, 1>(), 1);
+        assert_eq!(size::<PadU16, 2>(), 2);
+        assert_eq!(size::<PadU32, 4>(), 4);
+        assert_eq!(size::<PadU64, 8>(), 8);
+        assert_eq!(size::<PadWord, 8>(), 8);
+        assert_eq!(size::<PadSimd128Bytes, 16>(), 16);
+        assert_eq!(size::<PadBytes<13>, 13>(), 13);
+        assert_eq!(size::<ZeroedPad<PadU32>, 4>(), 4);
+        assert_eq!(size::<DontCarePad<PadBytes<3>>, 3>(), 3);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Pad;
+
+/// A tuple of padding types, with the total size of its elements.
+///
+/// `SIZE` is the sum of the sizes of the elements, which is the number of
+/// bytes the padding types occupy when laid out back to back. It does not
+/// include any padding the compiler inserts into the tuple itself. This is
+/// implemented for tuples of up to 8 padding types.
+///
+/// # Example
+///
+/// ```rust
+/// use struct_pad::{PadTupleSize, PadU32, PadU8};
+///
+/// assert_eq!(<(PadU32, PadU8) as PadTupleSize>::SIZE, 5);
+/// ```
+pub trait PadTupleSize {
+    /// The total size in bytes of the padding types in the tuple.
+    const SIZE: usize;
+}
+
+macro_rules! impl_tuple_size {
+    ($(($($ty:ident),*)),*) => {
+        $(
+            impl<$($ty: Pad),*> PadTupleSize for ($($ty,)*) {
+                const SIZE: usize = 0 $(+ $ty::SIZE)*;
+            }
+        )*
+    };
+}
+
+impl_tuple_size!(
+    (),
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PadBytes, PadU0, PadU16, PadU32, PadU64, PadU8};
+
+    #[test]
+    fn size() {
+        assert_eq!(<() as PadTupleSize>::SIZE, 0);
+        assert_eq!(<(PadU64,) as PadTupleSize>::SIZE, 8);
+        assert_eq!(<(PadU32, PadU8) as PadTupleSize>::SIZE, 5);
+        assert_eq!(<(PadU0, PadU16, PadBytes<3>) as PadTupleSize>::SIZE, 5);
+        assert_eq!(
+            <(PadU8, PadU8, PadU8, PadU8, PadU16, PadU16, PadU32, PadU64) as PadTupleSize>::SIZE,
+            20
+        );
+    }
+}